@@ -15,7 +15,7 @@ const functions: FunctionItem[] = [
   { label: "Moeda Corrente (endereço da conta)", name: "currency" },
   { label: "Valor Nominal (6 casas decimais)", name: "denomination" },
   { label: "Parcelas Amortizadas", name: "frequency" },
-  { label: "Taxa de Juros (%)", name: "interestRate" },
+  { label: "Remuneração (indexador, % do índice, spread, base)", name: "remuneration" },
   { label: "Emissora", name: "_name" },
   { label: "Conta de Pagamento à Emissora", name: "institution" },
 ];
@@ -6,11 +6,13 @@ import {
   BASE_FEE,
   Networks,
   scValToNative,
+  nativeToScVal,
 } from "soroban-client";
 
 /**
  * Service para consultar getters do BondStorage no Soroban.
  * - Modo online: usa import.meta.env.VITE_CONTRACT_ID e VITE_SECRET_KEY (opcional).
+ * - O contrato guarda várias séries; os getters recebem o ISIN (VITE_BOND_ISIN por padrão).
  * - Modo offline: retorna mocks para não quebrar a UI.
  */
 
@@ -38,6 +40,10 @@ function getContractId(): string | null {
   return import.meta.env.VITE_BONDSTORAGE_ID ?? import.meta.env.VITE_CONTRACT_ID ?? null;
 }
 
+function getDefaultIsin(): string {
+  return import.meta.env.VITE_BOND_ISIN ?? "BREMISDEB5H1";
+}
+
 /** Helper que converte scValToNative em string legível */
 function scValToString(scVal: any): string {
   try {
@@ -60,7 +66,7 @@ function scValToString(scVal: any): string {
  * Consulta um getter simples do contrato que não precisa de assinatura.
  * Retorna string (representação legível).
  */
-export async function getBondField(fieldName: string): Promise<string> {
+export async function getBondField(fieldName: string, isin: string = getDefaultIsin()): Promise<string> {
   const contractId = getContractId();
   const payer = getPayer();
 
@@ -68,13 +74,13 @@ export async function getBondField(fieldName: string): Promise<string> {
   if (!contractId || !payer) {
     console.warn("getBondField: modo offline (envs ausentes). Retornando mock.");
     const mocks: Record<string, string> = {
-      isin: "BREMISDEB5H2",
+      isin,
       name: "1ª Emissão - Série Única",
       symbol: "BREMIS",
       currency: "G... (endereço mock)",
       denomination: "1000000", // 1.000000 (considerando 6 casas)
       frequency: "8",
-      interestRate: "4",
+      remuneration: "CDI + 1,5% a.a. (DU/252)",
       _name: "Emissora XYZ",
       institution: "G... (endereço emissora)",
     };
@@ -86,8 +92,8 @@ export async function getBondField(fieldName: string): Promise<string> {
     const account = await server.getAccount(payer.publicKey());
     const contract = new Contract(contractId);
 
-    // monta operação de chamada do getter
-    const op = contract.call(fieldName);
+    // monta operação de chamada do getter da série
+    const op = contract.call(fieldName, nativeToScVal(isin, { type: "string" }));
 
     const tx = new TransactionBuilder(account, {
       fee: BASE_FEE,
@@ -115,10 +121,13 @@ export async function getBondField(fieldName: string): Promise<string> {
  * Consulta múltiplos campos em paralelo.
  * Retorna um mapa { fieldName: string }
  */
-export async function getBondFields(fieldNames: string[]): Promise<Record<string, string>> {
+export async function getBondFields(
+  fieldNames: string[],
+  isin: string = getDefaultIsin()
+): Promise<Record<string, string>> {
   // executa em paralelo com Promise.allSettled para tolerância a erros
   const promises = fieldNames.map((f) =>
-    getBondField(f)
+    getBondField(f, isin)
       .then((v) => ({ name: f, value: v }))
       .catch((e) => ({ name: f, value: `ERROR: ${String(e?.message ?? e)}` }))
   );
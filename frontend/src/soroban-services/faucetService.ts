@@ -39,7 +39,7 @@ function getContractId(): string | null {
 
 /* ---------- função pública: mint ---------- */
 /**
- * Executa mint_to_user() no contrato Soroban.
+ * Executa mint_to_user(user) no contrato Soroban.
  * Retorna txHash string se sucesso.
  */
 export async function mint(): Promise<string> {
@@ -66,9 +66,9 @@ export async function mint(): Promise<string> {
     // Ajuste fnName se o seu contrato tiver nome diferente (ex: mintToUser -> mint_to_user)
     const fnName = "mint_to_user";
 
-    // Se a função precisar de args, adicione aqui usando nativeToScVal com hint
+    // mint_to_user(user): o próprio usuário autoriza e recebe o mint
     const contract = new Contract(contractId);
-    const op = contract.call(fnName /*, ...args se precisar */);
+    const op = contract.call(fnName, nativeToScVal(payer.publicKey(), { type: "address" }));
 
     const tx = new TransactionBuilder(account, {
       fee: BASE_FEE,
@@ -42,6 +42,11 @@ function getContractId(): string | null {
   return import.meta.env.VITE_CONTRACT_ID ?? null;
 }
 
+/** ISIN da série consultada; o bond_storage guarda várias séries */
+function getIsin(): string {
+  return import.meta.env.VITE_BOND_ISIN ?? "BREMISDEB5H1";
+}
+
 /**
  * Busca preço do bond (denomination).
  * - Se variáveis ambientes estiverem ausentes, retorna um mock (1000000n -> 1.00 USDC em micros).
@@ -62,8 +67,8 @@ export async function getBondPrice(): Promise<bigint> {
     const account = await server.getAccount(payer.publicKey());
     const contract = new Contract(contractId);
 
-    // chama a função view 'denomination' (ajuste o nome se for outro)
-    const op = contract.call("denomination");
+    // chama a função view 'denomination' da série (ajuste o nome se for outro)
+    const op = contract.call("denomination", nativeToScVal(getIsin(), { type: "string" }));
 
     const tx = new TransactionBuilder(account, {
       fee: BASE_FEE,
@@ -1,169 +1,810 @@
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, panic_with_error, Error};
-use crate::{Error as AttorneyCoinError, TransferEvent, ApproveEvent, MintEvent};
+use soroban_sdk::{contract, contractimpl, contracttype, panic_with_error, symbol_short, token::TokenInterface, Address, Env, IntoVal, MuxedAddress, String, TryFromVal, Val, Vec};
+use crate::{Error as AttorneyCoinError, TransferEvent, ApproveEvent, MintEvent, BurnEvent, AdminProposedEvent, AdminChangedEvent, RoleEvent, FreezeEvent, UnfreezeEvent, ClawbackEvent, MaxSupplyEvent, FaucetEvent, SnapshotEvent, PauseEvent, UnpauseEvent};
 
 // Constants
 pub const DECIMALS: u32 = 6;
 pub const MINT_LIMIT: i128 = 10 * 10i128.pow(DECIMALS);
-
-// DataKey enum for storage
+pub const MINT_COOLDOWN: u64 = 86400;
+
+// Upper bound on `batch_transfer` legs. The binding limit is the per-transaction ledger
+// footprint: in the worst case (a snapshot is active) each leg reads four entries and
+// writes three (balance, checkpoint and checkpoint count). 12 legs stay under the 50
+// write / 100 read entry limits with headroom; see `test_batch_transfer_max_size_fits_budget`.
+pub const MAX_BATCH_SIZE: u32 = 12;
+pub const INITIAL_SUPPLY: i128 = 1_000_000 * 10i128.pow(DECIMALS);
+
+// TTL policy (~5s ledgers): instance data is bumped for a week, holder entries for a month
+pub const DAY_IN_LEDGERS: u32 = 17280;
+pub const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+pub const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+pub const BALANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const BALANCE_LIFETIME_THRESHOLD: u32 = BALANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+// DataKey enum for storage. Token metadata, admin data and faucet settings live in
+// instance storage; every per-address key lives in its own persistent entry, except
+// allowances which are temporary.
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
     Name,
     Symbol,
     TotalSupply,
+    MaxSupply,
     Balance(Address),
     Allowance(Address, Address),
     Admin,
+    PendingAdmin,
+    Minter(Address),
+    Pauser(Address),
+    Frozen(Address),
     UserLastMint(Address),
+    FaucetConfig,
+    FaucetUsage,
+    FaucetAllowed(Address),
+    Paused,
+    CurrentSnapshot,
+    Snapshot(u32),
+    BalanceCheckpoint(Address, u32),
+    BalanceCheckpointCount(Address),
+    SupplyCheckpoint(u32),
+    SupplyCheckpointCount,
+}
+
+// Allowance amount together with the ledger after which it is no longer valid
+#[derive(Clone)]
+#[contracttype]
+pub struct AllowanceValue {
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+// Faucet policy applied by `mint_to_user`, tunable by the admin
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct FaucetConfig {
+    pub enabled: bool,
+    // Balance a claim tops the user up to
+    pub amount: i128,
+    // Seconds between two claims of the same user
+    pub cooldown: u64,
+    // Maximum minted by the faucet per UTC day, across all users
+    pub daily_budget: Option<i128>,
+    pub allowlist_only: bool,
+}
+
+// Amount minted by the faucet on a given day (timestamp / 86400)
+#[derive(Clone)]
+#[contracttype]
+pub struct FaucetUsage {
+    pub day: u64,
+    pub minted: i128,
+}
+
+// Returned by `faucet_status`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct FaucetStatus {
+    pub next_eligible_at: u64,
+    pub claimable: i128,
+}
+
+// Ledger at which a snapshot was taken
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SnapshotInfo {
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+// Value a balance (or the total supply) had when `snapshot_id` was taken. Written lazily
+// by the first change after that snapshot, each in its own entry indexed 0..count.
+#[derive(Clone)]
+#[contracttype]
+pub struct Checkpoint {
+    pub snapshot_id: u32,
+    pub value: i128,
 }
 
 // Attorneycoin struct
 #[contract]
 pub struct Attorneycoin;
 
-// Attorneycoin contract implementation
-#[contractimpl]
-impl Attorneycoin {
-    // Initializes the contract with an admin and initial supply
-    pub fn init(env: Env, admin: Address) {
-        admin.require_auth();
+// -------------------------
+//  Internal helpers
+// -------------------------
 
-        env.storage().instance().set(&DataKey::Name, &"Attorneycoin");
-        env.storage().instance().set(&DataKey::Symbol, &"ATC");
-        env.storage().instance().set(&DataKey::Admin, &admin.clone());
+fn check_nonnegative_amount(env: &Env, amount: i128) {
+    if amount < 0 {
+        panic_with_error!(env, AttorneyCoinError::NegativeAmount);
+    }
+}
 
-        let initial_supply: i128 = 1_000_000 * 10i128.pow(DECIMALS);
-        env.storage().instance().set(&DataKey::TotalSupply, &initial_supply);
-        env.storage().instance().set(&DataKey::Balance(admin.clone()), &initial_supply);
+fn extend_instance_ttl(env: &Env) {
+    env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
 
-        env.events().publish((Symbol::new(&env, "mint"),), MintEvent{to: admin, amount: initial_supply});
+// Reads a per-address persistent entry, bumping its TTL when present
+fn read_persistent<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+    let value = env.storage().persistent().get(key);
+    if value.is_some() {
+        env.storage().persistent().extend_ttl(key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
     }
+    value
+}
 
-    // Returns the token's name
-    pub fn name(_env: Env) -> Symbol {
-        Symbol::new(&_env, "Attorneycoin")
+fn write_persistent<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    env.storage().persistent().extend_ttl(key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+fn read_balance(env: &Env, owner: &Address) -> i128 {
+    read_persistent(env, &DataKey::Balance(owner.clone())).unwrap_or(0)
+}
+
+fn write_balance(env: &Env, owner: &Address, amount: i128) {
+    let key = DataKey::Balance(owner.clone());
+    update_checkpoints(env, &Some(owner.clone()), read_balance(env, owner));
+    write_persistent(env, &key, &amount);
+    extend_instance_ttl(env);
+}
+
+fn receive_balance(env: &Env, owner: &Address, amount: i128) {
+    let balance = read_balance(env, owner);
+    write_balance(env, owner, balance + amount);
+}
+
+fn spend_balance(env: &Env, owner: &Address, amount: i128) {
+    let balance = read_balance(env, owner);
+    if balance < amount {
+        panic_with_error!(env, AttorneyCoinError::InsufficientBalance);
     }
+    write_balance(env, owner, balance - amount);
+}
 
-    // Returns the token's symbol
-    pub fn symbol(_env: Env) -> Symbol {
-        Symbol::new(&_env, "ATC")
+// Allowances live in temporary storage and are treated as zero once expired
+fn read_allowance(env: &Env, from: &Address, spender: &Address) -> AllowanceValue {
+    let key = DataKey::Allowance(from.clone(), spender.clone());
+    match env.storage().temporary().get::<_, AllowanceValue>(&key) {
+        Some(allowance) if allowance.expiration_ledger >= env.ledger().sequence() => allowance,
+        Some(allowance) => AllowanceValue { amount: 0, expiration_ledger: allowance.expiration_ledger },
+        None => AllowanceValue { amount: 0, expiration_ledger: 0 },
     }
+}
 
-    // Returns the token's decimals
-    pub fn decimals(_env: Env) -> u32 {
-        DECIMALS
+fn write_allowance(env: &Env, from: &Address, spender: &Address, amount: i128, expiration_ledger: u32) {
+    let sequence = env.ledger().sequence();
+    if amount > 0 && expiration_ledger < sequence {
+        panic_with_error!(env, AttorneyCoinError::InvalidExpirationLedger);
     }
 
-    // Returns the total supply of the token
-    pub fn total_supply(env: Env) -> i128 {
-        env.storage().instance().get(&DataKey::TotalSupply).unwrap_or(0)
+    let key = DataKey::Allowance(from.clone(), spender.clone());
+    env.storage().temporary().set(&key, &AllowanceValue { amount, expiration_ledger });
+
+    // Keep the entry alive exactly until the allowance expires
+    if amount > 0 {
+        let live_for = expiration_ledger - sequence;
+        env.storage().temporary().extend_ttl(&key, live_for, live_for);
     }
+}
 
-    // Returns the balance of a specific owner
-    pub fn balance_of(env: Env, owner: Address) -> i128 {
-        env.storage().instance().get(&DataKey::Balance(owner)).unwrap_or(0)
+fn spend_allowance(env: &Env, from: &Address, spender: &Address, amount: i128) {
+    let allowance = read_allowance(env, from, spender);
+    if allowance.amount < amount {
+        panic_with_error!(env, AttorneyCoinError::InsufficientAllowance);
     }
+    if amount > 0 {
+        write_allowance(env, from, spender, allowance.amount - amount, allowance.expiration_ledger);
+    }
+}
 
-    // Transfers a certain amount of tokens from the invoker to a recipient
-    pub fn transfer(env: Env, to: Address, amount: i128) {
-        let from = env.current_contract_address();
-        from.require_auth();
+fn read_total_supply(env: &Env) -> i128 {
+    env.storage().instance().get(&DataKey::TotalSupply).unwrap_or(0)
+}
+
+fn write_total_supply(env: &Env, total_supply: i128) {
+    update_checkpoints(env, &None, read_total_supply(env));
+    env.storage().instance().set(&DataKey::TotalSupply, &total_supply);
+}
+
+fn read_current_snapshot(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::CurrentSnapshot).unwrap_or(0)
+}
+
+// Checkpoints of an account's balance, or of the total supply when `owner` is None
+fn checkpoint_count_key(owner: &Option<Address>) -> DataKey {
+    match owner {
+        Some(owner) => DataKey::BalanceCheckpointCount(owner.clone()),
+        None => DataKey::SupplyCheckpointCount,
+    }
+}
+
+fn checkpoint_key(owner: &Option<Address>, index: u32) -> DataKey {
+    match owner {
+        Some(owner) => DataKey::BalanceCheckpoint(owner.clone(), index),
+        None => DataKey::SupplyCheckpoint(index),
+    }
+}
+
+fn read_checkpoint(env: &Env, owner: &Option<Address>, index: u32) -> Checkpoint {
+    read_persistent(env, &checkpoint_key(owner, index)).unwrap()
+}
+
+// Records `current_value` for the latest snapshot if this is the first change since it
+// was taken; must run before the value is overwritten. Writes at most two small entries
+// however many snapshots the account has been through.
+fn update_checkpoints(env: &Env, owner: &Option<Address>, current_value: i128) {
+    let snapshot_id = read_current_snapshot(env);
+    if snapshot_id == 0 {
+        return;
+    }
+
+    let count_key = checkpoint_count_key(owner);
+    let count: u32 = read_persistent(env, &count_key).unwrap_or(0);
+    let last_id = if count == 0 { 0 } else { read_checkpoint(env, owner, count - 1).snapshot_id };
+    if last_id < snapshot_id {
+        write_persistent(env, &checkpoint_key(owner, count), &Checkpoint { snapshot_id, value: current_value });
+        write_persistent(env, &count_key, &(count + 1));
+    }
+}
 
-        let from_balance = Self::balance_of(env.clone(), from.clone());
-        if from_balance < amount {
-            panic!("{:?}", AttorneyCoinError::InsufficientBalance);
+// Looks up the value as of `snapshot_id`: the first checkpoint written at or after that
+// snapshot holds it; if there is none, the value has not changed since. Binary search
+// over the checkpoint indexes, reading one entry per step.
+fn read_value_at(env: &Env, owner: &Option<Address>, snapshot_id: u32, current_value: i128) -> i128 {
+    if snapshot_id == 0 || snapshot_id > read_current_snapshot(env) {
+        panic_with_error!(env, AttorneyCoinError::InvalidSnapshot);
+    }
+
+    let count: u32 = read_persistent(env, &checkpoint_count_key(owner)).unwrap_or(0);
+    let (mut low, mut high) = (0, count);
+    while low < high {
+        let mid = (low + high) / 2;
+        if read_checkpoint(env, owner, mid).snapshot_id < snapshot_id {
+            low = mid + 1;
+        } else {
+            high = mid;
         }
+    }
+
+    if low == count {
+        current_value
+    } else {
+        read_checkpoint(env, owner, low).value
+    }
+}
 
-        env.storage().instance().set(&DataKey::Balance(from.clone()), &(from_balance - amount));
+fn read_admin(env: &Env) -> Address {
+    env.storage().instance().get(&DataKey::Admin).unwrap()
+}
 
-        let to_balance = Self::balance_of(env.clone(), to.clone());
-        env.storage().instance().set(&DataKey::Balance(to.clone()), &(to_balance + amount));
+// Loads the admin and requires its signature
+fn require_admin(env: &Env) -> Address {
+    let admin = read_admin(env);
+    admin.require_auth();
+    extend_instance_ttl(env);
+    admin
+}
 
-        env.events().publish((Symbol::new(&env, "transfer"),), TransferEvent{from, to, amount});
+// Boolean flags (roles, frozen accounts) are stored only while set
+fn read_flag(env: &Env, key: &DataKey) -> bool {
+    read_persistent(env, key).unwrap_or(false)
+}
+
+fn write_flag(env: &Env, key: DataKey, enabled: bool) {
+    if enabled {
+        write_persistent(env, &key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
     }
+}
 
-    // Approves a spender to withdraw a certain amount of tokens from the invoker's account
-    pub fn approve(env: Env, spender: Address, amount: i128) {
-        let owner = env.current_contract_address();
-        owner.require_auth();
-        env.storage().instance().set(&DataKey::Allowance(owner.clone(), spender.clone()), &amount);
+// While paused, every token movement and mint fails, clawbacks included; admin controls
+// (roles, freezes, faucet policy, snapshots) keep working
+fn check_not_paused(env: &Env) {
+    if env.storage().instance().get(&DataKey::Paused).unwrap_or(false) {
+        panic_with_error!(env, AttorneyCoinError::Paused);
+    }
+}
 
-        env.events().publish((Symbol::new(&env, "approve"),), ApproveEvent{from: owner, to: spender, amount});
+// Loads the pause authority (admin or pauser) and requires its signature
+fn require_pauser(env: &Env, caller: &Address) {
+    caller.require_auth();
+    if *caller != read_admin(env) && !read_flag(env, &DataKey::Pauser(caller.clone())) {
+        panic_with_error!(env, AttorneyCoinError::PauserOnly);
     }
+}
 
-    // Returns the amount of tokens a spender is allowed to withdraw from an owner's account
-    pub fn allowance(env: Env, owner: Address, spender: Address) -> i128 {
-        env.storage().instance().get(&DataKey::Allowance(owner, spender)).unwrap_or(0)
+// Frozen accounts can neither send nor receive ATC until unfrozen by the admin
+fn check_not_frozen(env: &Env, account: &Address) {
+    if read_flag(env, &DataKey::Frozen(account.clone())) {
+        panic_with_error!(env, AttorneyCoinError::AccountFrozen);
     }
+}
 
-    // Transfers a certain amount of tokens from a sender to a recipient, authorized by the sender
-    pub fn transfer_from(env: Env, from: Address, to: Address, amount: i128) {
-        let spender = env.current_contract_address();
-        spender.require_auth();
+fn read_max_supply(env: &Env) -> Option<i128> {
+    env.storage().instance().get(&DataKey::MaxSupply)
+}
+
+fn read_faucet_config(env: &Env) -> FaucetConfig {
+    env.storage().instance().get(&DataKey::FaucetConfig).unwrap_or(FaucetConfig {
+        enabled: true,
+        amount: MINT_LIMIT,
+        cooldown: MINT_COOLDOWN,
+        daily_budget: None,
+        allowlist_only: false,
+    })
+}
+
+fn read_faucet_minted_today(env: &Env) -> i128 {
+    let today = env.ledger().timestamp() / 86400;
+    match env.storage().instance().get::<_, FaucetUsage>(&DataKey::FaucetUsage) {
+        Some(usage) if usage.day == today => usage.minted,
+        _ => 0,
+    }
+}
+
+// Evaluates the faucet policy for `user`: when they may next claim, and either the amount
+// a claim would mint right now or the error currently blocking it
+fn check_faucet(env: &Env, user: &Address) -> (u64, Result<i128, AttorneyCoinError>) {
+    let config = read_faucet_config(env);
+    let now = env.ledger().timestamp();
 
-        let allowance = Self::allowance(env.clone(), from.clone(), spender.clone());
-        if allowance < amount {
-            panic!("{:?}", AttorneyCoinError::InsufficientAllowance);
+    let last_mint: Option<u64> = read_persistent(env, &DataKey::UserLastMint(user.clone()));
+    let mut next_eligible_at = last_mint.map_or(now, |last_mint| now.max(last_mint.saturating_add(config.cooldown)));
+
+    if !config.enabled {
+        return (next_eligible_at, Err(AttorneyCoinError::FaucetDisabled));
+    }
+    if config.allowlist_only && !read_flag(env, &DataKey::FaucetAllowed(user.clone())) {
+        return (next_eligible_at, Err(AttorneyCoinError::NotAllowlisted));
+    }
+    if next_eligible_at > now {
+        return (next_eligible_at, Err(AttorneyCoinError::DailyMintingReached));
+    }
+
+    let current_balance = read_balance(env, user);
+    if current_balance >= config.amount {
+        return (next_eligible_at, Err(AttorneyCoinError::MintingCapped));
+    }
+
+    let claimable = config.amount - current_balance;
+    if let Some(daily_budget) = config.daily_budget {
+        if read_faucet_minted_today(env) + claimable > daily_budget {
+            next_eligible_at = (now / 86400 + 1) * 86400;
+            return (next_eligible_at, Err(AttorneyCoinError::FaucetBudgetExhausted));
         }
+    }
 
-        let from_balance = Self::balance_of(env.clone(), from.clone());
-        if from_balance < amount {
-            panic!("{:?}", AttorneyCoinError::InsufficientBalance);
+    (next_eligible_at, Ok(claimable))
+}
+
+// Every mint path goes through here, so the hard cap and the freeze hold for all of them
+fn mint(env: &Env, to: &Address, amount: i128) {
+    check_not_frozen(env, to);
+
+    let new_supply = read_total_supply(env) + amount;
+    if let Some(max_supply) = read_max_supply(env) {
+        if new_supply > max_supply {
+            panic_with_error!(env, AttorneyCoinError::MintingCapped);
         }
+    }
 
-        env.storage().instance().set(&DataKey::Allowance(from.clone(), spender.clone()), &(allowance - amount));
-        env.storage().instance().set(&DataKey::Balance(from.clone()), &(from_balance - amount));
+    receive_balance(env, to, amount);
+    write_total_supply(env, new_supply);
 
-        let to_balance = Self::balance_of(env.clone(), to.clone());
-        env.storage().instance().set(&DataKey::Balance(to.clone()), &(to_balance + amount));
+    MintEvent { to: to.clone(), amount }.publish(env);
+}
+
+fn burn(env: &Env, from: &Address, amount: i128) {
+    spend_balance(env, from, amount);
+    write_total_supply(env, read_total_supply(env) - amount);
+
+    BurnEvent { from: from.clone(), amount }.publish(env);
+}
 
-        env.events().publish((Symbol::new(&env, "transfer"),), TransferEvent{from, to, amount});
+// Attorneycoin contract implementation
+#[contractimpl]
+impl Attorneycoin {
+    // Sets the admin, the initial supply and an optional hard cap at deploy time. It never
+    // runs again, so nobody can claim a fresh deployment before its owner
+    pub fn __constructor(env: Env, admin: Address, max_supply: Option<i128>) {
+        env.storage().instance().set(&DataKey::Name, &String::from_str(&env, "Attorneycoin"));
+        env.storage().instance().set(&DataKey::Symbol, &String::from_str(&env, "ATC"));
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        if let Some(max_supply) = max_supply {
+            env.storage().instance().set(&DataKey::MaxSupply, &max_supply);
+        }
+        extend_instance_ttl(&env);
+
+        mint(&env, &admin, INITIAL_SUPPLY);
     }
 
-    // Mints new tokens to the user, with a daily limit
-    pub fn mint_to_user(env: Env) {
-        let user = env.current_contract_address();
-        user.require_auth();
+    // Returns the hard cap on total supply, if one was set
+    pub fn max_supply(env: Env) -> Option<i128> {
+        read_max_supply(&env)
+    }
 
-        let now = env.ledger().timestamp();
-        let last_mint = env.storage().instance().get(&DataKey::UserLastMint(user.clone())).unwrap_or(0);
+    // Returns how much can still be minted before reaching the cap (None when uncapped)
+    pub fn remaining_mintable(env: Env) -> Option<i128> {
+        read_max_supply(&env).map(|max_supply| max_supply - read_total_supply(&env))
+    }
+
+    // Lowers the hard cap (or sets one on an uncapped token); it can never be raised
+    pub fn set_max_supply(env: Env, max_supply: i128) {
+        require_admin(&env);
 
-        if now - last_mint < 86400 {
-            panic!("{:?}", AttorneyCoinError::DailyMintingReached);
+        let exceeds_current = read_max_supply(&env).is_some_and(|current| max_supply > current);
+        if exceeds_current || max_supply < read_total_supply(&env) {
+            panic_with_error!(&env, AttorneyCoinError::InvalidMaxSupply);
         }
+        env.storage().instance().set(&DataKey::MaxSupply, &max_supply);
+
+        MaxSupplyEvent { max_supply }.publish(&env);
+    }
 
-        let current_balance = Self::balance_of(env.clone(), user.clone());
-        if current_balance >= MINT_LIMIT {
-            panic!("{:?}", AttorneyCoinError::MintingCapped);
+    // Returns the total supply of the token
+    pub fn total_supply(env: Env) -> i128 {
+        read_total_supply(&env)
+    }
+
+    // Returns the balance of a specific owner (alias of `balance`)
+    pub fn balance_of(env: Env, owner: Address) -> i128 {
+        read_balance(&env, &owner)
+    }
+
+    // Sends ATC from one owner to many recipients in a single atomic call
+    pub fn batch_transfer(env: Env, from: Address, transfers: Vec<(Address, i128)>) {
+        check_not_paused(&env);
+        from.require_auth();
+        if transfers.len() > MAX_BATCH_SIZE {
+            panic_with_error!(&env, AttorneyCoinError::BatchTooLarge);
+        }
+        check_not_frozen(&env, &from);
+
+        let mut total: i128 = 0;
+        for (to, amount) in transfers.iter() {
+            check_nonnegative_amount(&env, amount);
+            check_not_frozen(&env, &to);
+            total = total.checked_add(amount)
+                .unwrap_or_else(|| panic_with_error!(&env, AttorneyCoinError::AmountOverflow));
+        }
+        spend_balance(&env, &from, total);
+
+        for (to, amount) in transfers.iter() {
+            receive_balance(&env, &to, amount);
+            TransferEvent { from: from.clone(), to, to_muxed_id: None, amount }.publish(&env);
         }
+    }
+
+    // Faucet: tops the user up to the configured amount, subject to the faucet policy
+    pub fn mint_to_user(env: Env, user: Address) {
+        check_not_paused(&env);
+        user.require_auth();
+
+        let amount = match check_faucet(&env, &user).1 {
+            Ok(amount) => amount,
+            Err(error) => panic_with_error!(&env, error),
+        };
 
-        let minted = MINT_LIMIT - current_balance;
+        let now = env.ledger().timestamp();
+        let usage = FaucetUsage { day: now / 86400, minted: read_faucet_minted_today(&env) + amount };
+        env.storage().instance().set(&DataKey::FaucetUsage, &usage);
+        write_persistent(&env, &DataKey::UserLastMint(user.clone()), &now);
+        mint(&env, &user, amount);
+
+        FaucetEvent { user, amount }.publish(&env);
+    }
 
-        env.storage().instance().set(&DataKey::Balance(user.clone()), &(current_balance + minted));
-        let total_supply = Self::total_supply(env.clone()) + minted;
-        env.storage().instance().set(&DataKey::TotalSupply, &total_supply);
-        env.storage().instance().set(&DataKey::UserLastMint(user.clone()), &now);
+    // Returns when `user` may next use the faucet and how much a claim would mint right now
+    pub fn faucet_status(env: Env, user: Address) -> FaucetStatus {
+        let (next_eligible_at, claim) = check_faucet(&env, &user);
+        FaucetStatus { next_eligible_at, claimable: claim.unwrap_or(0) }
+    }
 
-        env.events().publish((Symbol::new(&env, "mint"),), MintEvent{to: user, amount: minted});
+    // Returns the faucet policy currently in force
+    pub fn faucet_config(env: Env) -> FaucetConfig {
+        read_faucet_config(&env)
+    }
+
+    // Replaces the faucet policy
+    pub fn set_faucet_config(env: Env, config: FaucetConfig) {
+        require_admin(&env);
+        check_nonnegative_amount(&env, config.amount);
+        check_nonnegative_amount(&env, config.daily_budget.unwrap_or(0));
+
+        env.storage().instance().set(&DataKey::FaucetConfig, &config);
+    }
+
+    // Adds or removes an address from the faucet allowlist
+    pub fn set_faucet_allowed(env: Env, account: Address, allowed: bool) {
+        require_admin(&env);
+        write_flag(&env, DataKey::FaucetAllowed(account), allowed);
     }
 
     // Mints new tokens to the admin, with no limit
     pub fn admin_mint(env: Env, amount: i128) {
-        let caller = env.current_contract_address();
-        caller.require_auth();
+        check_not_paused(&env);
+        check_nonnegative_amount(&env, amount);
+
+        let admin = require_admin(&env);
+        mint(&env, &admin, amount);
+    }
+
+    // Mints new tokens to any address, restricted to the admin or a minter
+    pub fn mint(env: Env, minter: Address, to: Address, amount: i128) {
+        check_not_paused(&env);
+        minter.require_auth();
+        check_nonnegative_amount(&env, amount);
 
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        if caller != admin {
-            panic!("{:?}", AttorneyCoinError::AdminOnly);
+        if minter != read_admin(&env) && !read_flag(&env, &DataKey::Minter(minter.clone())) {
+            panic_with_error!(&env, AttorneyCoinError::MinterOnly);
         }
 
-        let admin_balance = Self::balance_of(env.clone(), admin.clone());
-        env.storage().instance().set(&DataKey::Balance(admin.clone()), &(admin_balance + amount));
+        mint(&env, &to, amount);
+    }
+
+    // ------------------------
+    //   Admin and roles
+    // ------------------------
+
+    // Returns the current admin
+    pub fn admin(env: Env) -> Address {
+        read_admin(&env)
+    }
+
+    // Returns the admin waiting to accept the role, if any
+    pub fn pending_admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PendingAdmin)
+    }
+
+    // First step of the admin rotation: the current admin nominates a successor
+    pub fn propose_admin(env: Env, new_admin: Address) {
+        let admin = require_admin(&env);
+        env.storage().instance().set(&DataKey::PendingAdmin, &new_admin);
 
-        let new_supply = Self::total_supply(env.clone()) + amount;
-        env.storage().instance().set(&DataKey::TotalSupply, &new_supply);
+        AdminProposedEvent { admin, new_admin }.publish(&env);
+    }
+
+    // Second step of the admin rotation: the nominee accepts and becomes admin
+    pub fn accept_admin(env: Env) {
+        let new_admin: Address = env.storage().instance().get(&DataKey::PendingAdmin)
+            .unwrap_or_else(|| panic_with_error!(&env, AttorneyCoinError::NoPendingAdmin));
+        new_admin.require_auth();
+
+        let previous_admin = read_admin(&env);
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+
+        AdminChangedEvent { previous_admin, new_admin }.publish(&env);
+    }
+
+    // Grants or revokes the minter role
+    pub fn set_minter(env: Env, account: Address, enabled: bool) {
+        require_admin(&env);
+        write_flag(&env, DataKey::Minter(account.clone()), enabled);
+
+        RoleEvent { role: symbol_short!("minter"), account, enabled }.publish(&env);
+    }
+
+    // Returns whether an address holds the minter role
+    pub fn is_minter(env: Env, account: Address) -> bool {
+        read_flag(&env, &DataKey::Minter(account))
+    }
+
+    // Grants or revokes the pauser role
+    pub fn set_pauser(env: Env, account: Address, enabled: bool) {
+        require_admin(&env);
+        write_flag(&env, DataKey::Pauser(account.clone()), enabled);
+
+        RoleEvent { role: symbol_short!("pauser"), account, enabled }.publish(&env);
+    }
+
+    // Returns whether an address holds the pauser role
+    pub fn is_pauser(env: Env, account: Address) -> bool {
+        read_flag(&env, &DataKey::Pauser(account))
+    }
+
+    // Emergency stop, callable by the admin or a pauser
+    pub fn pause(env: Env, caller: Address) {
+        require_pauser(&env, &caller);
+        env.storage().instance().set(&DataKey::Paused, &true);
 
-        env.events().publish((Symbol::new(&env, "mint"),), MintEvent{to: admin, amount});
+        PauseEvent { caller }.publish(&env);
     }
-}
\ No newline at end of file
+
+    // Lifts the emergency stop, callable by the admin or a pauser
+    pub fn unpause(env: Env, caller: Address) {
+        require_pauser(&env, &caller);
+        env.storage().instance().set(&DataKey::Paused, &false);
+
+        UnpauseEvent { caller }.publish(&env);
+    }
+
+    // Returns whether the token is paused
+    pub fn paused(env: Env) -> bool {
+        env.storage().instance().get(&DataKey::Paused).unwrap_or(false)
+    }
+
+    // ------------------------
+    //   Regulatory controls
+    // ------------------------
+
+    // Blocks an account from sending or receiving ATC (e.g. under court order)
+    pub fn freeze(env: Env, account: Address) {
+        require_admin(&env);
+        write_flag(&env, DataKey::Frozen(account.clone()), true);
+
+        FreezeEvent { account }.publish(&env);
+    }
+
+    // Lifts a previous freeze
+    pub fn unfreeze(env: Env, account: Address) {
+        require_admin(&env);
+        write_flag(&env, DataKey::Frozen(account.clone()), false);
+
+        UnfreezeEvent { account }.publish(&env);
+    }
+
+    // Returns whether an account is frozen
+    pub fn is_frozen(env: Env, account: Address) -> bool {
+        read_flag(&env, &DataKey::Frozen(account))
+    }
+
+    // Seizes tokens from an account and burns them, regardless of freeze status
+    pub fn clawback(env: Env, from: Address, amount: i128) {
+        check_not_paused(&env);
+        check_nonnegative_amount(&env, amount);
+        require_admin(&env);
+
+        burn(&env, &from, amount);
+
+        ClawbackEvent { from, to: None, amount }.publish(&env);
+    }
+
+    // Seizes tokens from an account and moves them to `to`, regardless of the source's
+    // freeze status; `to` itself must not be frozen
+    pub fn clawback_to(env: Env, from: Address, to: Address, amount: i128) {
+        check_not_paused(&env);
+        check_nonnegative_amount(&env, amount);
+        require_admin(&env);
+        check_not_frozen(&env, &to);
+
+        spend_balance(&env, &from, amount);
+        receive_balance(&env, &to, amount);
+
+        TransferEvent { from: from.clone(), to: to.clone(), to_muxed_id: None, amount }.publish(&env);
+        ClawbackEvent { from, to: Some(to), amount }.publish(&env);
+    }
+
+    // ------------------------
+    //   Snapshots
+    // ------------------------
+
+    // Takes a snapshot of all balances and the total supply, returning its id
+    pub fn snapshot(env: Env) -> u32 {
+        require_admin(&env);
+
+        let snapshot_id = read_current_snapshot(&env) + 1;
+        let info = SnapshotInfo { ledger: env.ledger().sequence(), timestamp: env.ledger().timestamp() };
+        env.storage().instance().set(&DataKey::CurrentSnapshot, &snapshot_id);
+        write_persistent(&env, &DataKey::Snapshot(snapshot_id), &info);
+
+        SnapshotEvent { snapshot_id, ledger: info.ledger, timestamp: info.timestamp }.publish(&env);
+        snapshot_id
+    }
+
+    // Returns the id of the latest snapshot (0 if none was taken)
+    pub fn current_snapshot_id(env: Env) -> u32 {
+        read_current_snapshot(&env)
+    }
+
+    // Returns the ledger and timestamp at which a snapshot was taken
+    pub fn snapshot_info(env: Env, snapshot_id: u32) -> SnapshotInfo {
+        read_persistent(&env, &DataKey::Snapshot(snapshot_id))
+            .unwrap_or_else(|| panic_with_error!(&env, AttorneyCoinError::InvalidSnapshot))
+    }
+
+    // Returns the balance an owner had when the snapshot was taken
+    pub fn balance_of_at(env: Env, owner: Address, snapshot_id: u32) -> i128 {
+        let current_value = read_balance(&env, &owner);
+        read_value_at(&env, &Some(owner), snapshot_id, current_value)
+    }
+
+    // Returns the total supply when the snapshot was taken
+    pub fn total_supply_at(env: Env, snapshot_id: u32) -> i128 {
+        let current_value = read_total_supply(&env);
+        read_value_at(&env, &None, snapshot_id, current_value)
+    }
+}
+
+// SEP-41 token interface, so ATC can be driven through `soroban_sdk::token::Client`
+#[contractimpl]
+impl TokenInterface for Attorneycoin {
+    // Returns the live amount a spender is allowed to withdraw from an owner's account
+    fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        read_allowance(&env, &from, &spender).amount
+    }
+
+    // Approves a spender to withdraw tokens from the owner's account until `expiration_ledger`
+    fn approve(env: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        check_not_paused(&env);
+        from.require_auth();
+        check_nonnegative_amount(&env, amount);
+
+        write_allowance(&env, &from, &spender, amount, expiration_ledger);
+
+        ApproveEvent { from, spender, amount, expiration_ledger }.publish(&env);
+    }
+
+    // Returns the balance of a specific owner
+    fn balance(env: Env, id: Address) -> i128 {
+        read_balance(&env, &id)
+    }
+
+    // Transfers a certain amount of tokens from the owner to a recipient
+    fn transfer(env: Env, from: Address, to: MuxedAddress, amount: i128) {
+        check_not_paused(&env);
+        from.require_auth();
+        check_nonnegative_amount(&env, amount);
+
+        let to_muxed_id = to.id();
+        let to = to.address();
+        check_not_frozen(&env, &from);
+        check_not_frozen(&env, &to);
+        spend_balance(&env, &from, amount);
+        receive_balance(&env, &to, amount);
+
+        TransferEvent { from, to, to_muxed_id, amount }.publish(&env);
+    }
+
+    // Transfers tokens from an owner to a recipient, consuming the spender's allowance
+    fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        check_not_paused(&env);
+        spender.require_auth();
+        check_nonnegative_amount(&env, amount);
+
+        check_not_frozen(&env, &from);
+        check_not_frozen(&env, &to);
+        spend_allowance(&env, &from, &spender, amount);
+        spend_balance(&env, &from, amount);
+        receive_balance(&env, &to, amount);
+
+        TransferEvent { from, to, to_muxed_id: None, amount }.publish(&env);
+    }
+
+    // Burns tokens from the owner's balance, reducing the total supply
+    fn burn(env: Env, from: Address, amount: i128) {
+        check_not_paused(&env);
+        from.require_auth();
+        check_nonnegative_amount(&env, amount);
+        check_not_frozen(&env, &from);
+
+        burn(&env, &from, amount);
+    }
+
+    // Burns tokens from an owner's balance, consuming the spender's allowance
+    fn burn_from(env: Env, spender: Address, from: Address, amount: i128) {
+        check_not_paused(&env);
+        spender.require_auth();
+        check_nonnegative_amount(&env, amount);
+        check_not_frozen(&env, &from);
+
+        spend_allowance(&env, &from, &spender, amount);
+        burn(&env, &from, amount);
+    }
+
+    // Returns the token's decimals
+    fn decimals(_env: Env) -> u32 {
+        DECIMALS
+    }
+
+    // Returns the token's name
+    fn name(env: Env) -> String {
+        env.storage().instance().get(&DataKey::Name).unwrap()
+    }
+
+    // Returns the token's symbol
+    fn symbol(env: Env) -> String {
+        env.storage().instance().get(&DataKey::Symbol).unwrap()
+    }
+}
@@ -2,28 +2,149 @@
 
 pub mod attorneycoin;
 
-use soroban_sdk::{contracterror, contracttype, Address, Symbol};
+#[cfg(test)]
+pub mod test;
 
-#[contracttype]
+use soroban_sdk::{contracterror, contractevent, Address, Symbol};
+
+// SEP-41 events: topics ["transfer", from, to], data { to_muxed_id, amount }
+// (`to_muxed_id` is None unless the recipient is a muxed account)
+#[contractevent(topics = ["transfer"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TransferEvent {
+    #[topic]
     pub from: Address,
+    #[topic]
     pub to: Address,
+    pub to_muxed_id: Option<u64>,
     pub amount: i128,
 }
 
-#[contracttype]
+// topics ["approve", from, spender], data [amount, expiration_ledger]
+#[contractevent(topics = ["approve"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ApproveEvent {
+    #[topic]
     pub from: Address,
-    pub to: Address,
+    #[topic]
+    pub spender: Address,
     pub amount: i128,
+    pub expiration_ledger: u32,
 }
 
-#[contracttype]
+// topics ["mint", to], data amount
+#[contractevent(topics = ["mint"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct MintEvent {
+    #[topic]
     pub to: Address,
     pub amount: i128,
 }
 
+// topics ["burn", from], data amount
+#[contractevent(topics = ["burn"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BurnEvent {
+    #[topic]
+    pub from: Address,
+    pub amount: i128,
+}
+
+// topics ["admin_proposed", admin], data new_admin
+#[contractevent(topics = ["admin_proposed"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminProposedEvent {
+    #[topic]
+    pub admin: Address,
+    pub new_admin: Address,
+}
+
+// topics ["set_admin", previous_admin], data new_admin
+#[contractevent(topics = ["set_admin"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminChangedEvent {
+    #[topic]
+    pub previous_admin: Address,
+    pub new_admin: Address,
+}
+
+// topics ["role", role, account], data enabled
+#[contractevent(topics = ["role"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleEvent {
+    #[topic]
+    pub role: Symbol,
+    #[topic]
+    pub account: Address,
+    pub enabled: bool,
+}
+
+// topics ["freeze", account]
+#[contractevent(topics = ["freeze"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FreezeEvent {
+    #[topic]
+    pub account: Address,
+}
+
+// topics ["unfreeze", account]
+#[contractevent(topics = ["unfreeze"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnfreezeEvent {
+    #[topic]
+    pub account: Address,
+}
+
+// topics ["clawback", from], data { to, amount } (`to` is None when burned)
+#[contractevent(topics = ["clawback"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClawbackEvent {
+    #[topic]
+    pub from: Address,
+    pub to: Option<Address>,
+    pub amount: i128,
+}
+
+// topics ["max_supply"], data max_supply
+#[contractevent(topics = ["max_supply"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaxSupplyEvent {
+    pub max_supply: i128,
+}
+
+// topics ["faucet", user], data amount
+#[contractevent(topics = ["faucet"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FaucetEvent {
+    #[topic]
+    pub user: Address,
+    pub amount: i128,
+}
+
+// topics ["snapshot", snapshot_id], data [ledger, timestamp]
+#[contractevent(topics = ["snapshot"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SnapshotEvent {
+    #[topic]
+    pub snapshot_id: u32,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+// topics ["pause"], data caller
+#[contractevent(topics = ["pause"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseEvent {
+    pub caller: Address,
+}
+
+// topics ["unpause"], data caller
+#[contractevent(topics = ["unpause"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnpauseEvent {
+    pub caller: Address,
+}
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -33,7 +154,18 @@ pub enum Error {
     MintingCapped = 3,
     DailyMintingReached = 4,
     AdminOnly = 5,
+    NegativeAmount = 6,
+    InvalidExpirationLedger = 7,
+    NoPendingAdmin = 9,
+    MinterOnly = 10,
+    AccountFrozen = 11,
+    InvalidMaxSupply = 12,
+    FaucetDisabled = 13,
+    NotAllowlisted = 14,
+    FaucetBudgetExhausted = 15,
+    InvalidSnapshot = 16,
+    Paused = 17,
+    PauserOnly = 18,
+    BatchTooLarge = 19,
+    AmountOverflow = 20,
 }
-
-
-
@@ -0,0 +1,758 @@
+#![cfg(test)]
+
+extern crate std;
+
+use super::attorneycoin::{Attorneycoin, AttorneycoinClient, FaucetConfig, FaucetStatus, DECIMALS, INITIAL_SUPPLY, MAX_BATCH_SIZE, MINT_LIMIT};
+use super::Error;
+use debenture_types::testutils::{measure, Footprint};
+use soroban_sdk::testutils::{Address as _, EnvTestConfig, Events, Ledger, MockAuth, MockAuthInvoke, MuxedAddress as _};
+use soroban_sdk::{map, symbol_short, token, vec, Address, Env, IntoVal, Map, MuxedAddress, String, Symbol, Val, Vec};
+
+fn deploy_contract<'a>(env: &Env) -> (Address, AttorneycoinClient<'a>, Address) {
+    let admin = Address::generate(env);
+    let contract_id = env.register(Attorneycoin, (&admin, None::<i128>));
+    let client = AttorneycoinClient::new(env, &contract_id);
+    env.mock_all_auths();
+    (contract_id, client, admin)
+}
+
+#[test]
+fn test_initialization() {
+    let env = Env::default();
+    let (_contract_id, client, admin) = deploy_contract(&env);
+
+    assert_eq!(client.name(), String::from_str(&env, "Attorneycoin"));
+    assert_eq!(client.symbol(), String::from_str(&env, "ATC"));
+    assert_eq!(client.decimals(), DECIMALS);
+    assert_eq!(client.total_supply(), INITIAL_SUPPLY);
+    assert_eq!(client.balance(&admin), INITIAL_SUPPLY);
+}
+
+#[test]
+fn test_transfer_through_token_client() {
+    let env = Env::default();
+    let (contract_id, _client, admin) = deploy_contract(&env);
+    let token = token::Client::new(&env, &contract_id);
+    let user = Address::generate(&env);
+
+    token.transfer(&admin, &user, &1_000);
+    assert_eq!(token.balance(&user), 1_000);
+    assert_eq!(token.balance(&admin), INITIAL_SUPPLY - 1_000);
+
+    token.transfer(&user, &admin, &400);
+    assert_eq!(token.balance(&user), 600);
+}
+
+// SEP-41: the transfer event carries the recipient's muxed id, and None for plain accounts
+#[test]
+fn test_transfer_to_muxed_account() {
+    let env = Env::default();
+    let (contract_id, client, admin) = deploy_contract(&env);
+    let plain = Address::generate(&env);
+    let muxed = MuxedAddress::new(MuxedAddress::generate(&env), 42);
+
+    client.transfer(&admin, &plain, &100);
+    let data: Map<Symbol, Val> = map![
+        &env,
+        (Symbol::new(&env, "amount"), 100i128.into_val(&env)),
+        (Symbol::new(&env, "to_muxed_id"), ().into_val(&env)),
+    ];
+    assert_eq!(
+        env.events().all(),
+        vec![&env, (contract_id.clone(), (symbol_short!("transfer"), admin.clone(), plain).into_val(&env), data.into_val(&env))]
+    );
+
+    client.transfer(&admin, &muxed, &250);
+    let data: Map<Symbol, Val> = map![
+        &env,
+        (Symbol::new(&env, "amount"), 250i128.into_val(&env)),
+        (Symbol::new(&env, "to_muxed_id"), 42u64.into_val(&env)),
+    ];
+    assert_eq!(
+        env.events().all(),
+        vec![&env, (contract_id, (symbol_short!("transfer"), admin, muxed.address()).into_val(&env), data.into_val(&env))]
+    );
+    assert_eq!(client.balance(&muxed.address()), 250);
+}
+
+#[test]
+fn test_approve_and_transfer_from() {
+    let env = Env::default();
+    let (contract_id, client, admin) = deploy_contract(&env);
+    let token = token::Client::new(&env, &contract_id);
+    let spender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    token.approve(&admin, &spender, &500, &1_000);
+    assert_eq!(token.allowance(&admin, &spender), 500);
+
+    token.transfer_from(&spender, &admin, &recipient, &300);
+    assert_eq!(token.balance(&recipient), 300);
+    assert_eq!(token.allowance(&admin, &spender), 200);
+
+    let result = client.try_transfer_from(&spender, &admin, &recipient, &201);
+    assert_eq!(result, Err(Ok(Error::InsufficientAllowance.into())));
+}
+
+#[test]
+fn test_transfer_insufficient_balance() {
+    let env = Env::default();
+    let (_contract_id, client, admin) = deploy_contract(&env);
+    let user = Address::generate(&env);
+
+    let result = client.try_transfer(&user, &admin, &1);
+    assert_eq!(result, Err(Ok(Error::InsufficientBalance.into())));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn test_unauthorized_transfer() {
+    let env = Env::default();
+    let (_contract_id, client, admin) = deploy_contract(&env);
+    let thief = Address::generate(&env);
+
+    env.set_auths(&[]);
+    client.transfer(&admin, &thief, &1);
+}
+
+#[test]
+fn test_burn_and_burn_from() {
+    let env = Env::default();
+    let (_contract_id, client, admin) = deploy_contract(&env);
+    let spender = Address::generate(&env);
+
+    client.burn(&admin, &1_000);
+    assert_eq!(client.total_supply(), INITIAL_SUPPLY - 1_000);
+
+    client.approve(&admin, &spender, &500, &1_000);
+    client.burn_from(&spender, &admin, &500);
+    assert_eq!(client.balance(&admin), INITIAL_SUPPLY - 1_500);
+    assert_eq!(client.total_supply(), INITIAL_SUPPLY - 1_500);
+}
+
+#[test]
+fn test_mint_to_user_daily_limit() {
+    let env = Env::default();
+    let (_contract_id, client, _admin) = deploy_contract(&env);
+    let user = Address::generate(&env);
+
+    client.mint_to_user(&user);
+    assert_eq!(client.balance(&user), MINT_LIMIT);
+
+    let result = client.try_mint_to_user(&user);
+    assert_eq!(result, Err(Ok(Error::DailyMintingReached.into())));
+
+    env.ledger().with_mut(|li| li.timestamp += 86400);
+    let result = client.try_mint_to_user(&user);
+    assert_eq!(result, Err(Ok(Error::MintingCapped.into())));
+}
+
+#[test]
+fn test_admin_mint() {
+    let env = Env::default();
+    let (_contract_id, client, admin) = deploy_contract(&env);
+
+    client.admin_mint(&5_000);
+    assert_eq!(client.balance(&admin), INITIAL_SUPPLY + 5_000);
+    assert_eq!(client.total_supply(), INITIAL_SUPPLY + 5_000);
+}
+
+#[test]
+fn test_allowance_expires() {
+    let env = Env::default();
+    let (_contract_id, client, admin) = deploy_contract(&env);
+    let spender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    client.approve(&admin, &spender, &500, &200);
+    assert_eq!(client.allowance(&admin, &spender), 500);
+
+    // Partial spend keeps the original expiration
+    client.transfer_from(&spender, &admin, &recipient, &100);
+    env.ledger().with_mut(|li| li.sequence_number = 200);
+    assert_eq!(client.allowance(&admin, &spender), 400);
+
+    env.ledger().with_mut(|li| li.sequence_number = 201);
+    assert_eq!(client.allowance(&admin, &spender), 0);
+
+    let result = client.try_transfer_from(&spender, &admin, &recipient, &1);
+    assert_eq!(result, Err(Ok(Error::InsufficientAllowance.into())));
+}
+
+#[test]
+fn test_approve_with_past_expiration() {
+    let env = Env::default();
+    let (_contract_id, client, admin) = deploy_contract(&env);
+    let spender = Address::generate(&env);
+
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    let result = client.try_approve(&admin, &spender, &500, &99);
+    assert_eq!(result, Err(Ok(Error::InvalidExpirationLedger.into())));
+
+    // Revoking with a zero amount is always allowed
+    client.approve(&admin, &spender, &0, &0);
+    assert_eq!(client.allowance(&admin, &spender), 0);
+}
+
+#[test]
+fn test_burn_emits_event() {
+    let env = Env::default();
+    let (contract_id, client, admin) = deploy_contract(&env);
+
+    client.burn(&admin, &1_000);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_id,
+                (symbol_short!("burn"), admin.clone()).into_val(&env),
+                1_000i128.into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_burn_error_codes() {
+    let env = Env::default();
+    let (_contract_id, client, admin) = deploy_contract(&env);
+    let holder = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    client.transfer(&admin, &holder, &100);
+    let result = client.try_burn(&holder, &101);
+    assert_eq!(result, Err(Ok(Error::InsufficientBalance.into())));
+
+    client.approve(&holder, &spender, &50, &1_000);
+    let result = client.try_burn_from(&spender, &holder, &51);
+    assert_eq!(result, Err(Ok(Error::InsufficientAllowance.into())));
+
+    client.approve(&holder, &spender, &500, &1_000);
+    let result = client.try_burn_from(&spender, &holder, &101);
+    assert_eq!(result, Err(Ok(Error::InsufficientBalance.into())));
+
+    assert_eq!(client.balance(&holder), 100);
+    assert_eq!(client.total_supply(), INITIAL_SUPPLY);
+}
+
+// The constructor only runs at deploy time; calling it afterwards cannot replace the admin
+#[test]
+fn test_constructor_runs_once() {
+    let env = Env::default();
+    let (contract_id, client, admin) = deploy_contract(&env);
+
+    let args = vec![&env, Address::generate(&env).into_val(&env), None::<i128>.into_val(&env)];
+    let result = env.try_invoke_contract::<(), Error>(&contract_id, &Symbol::new(&env, "__constructor"), args);
+    assert!(result.is_err());
+    assert_eq!(client.admin(), admin);
+    assert_eq!(client.total_supply(), INITIAL_SUPPLY);
+}
+
+#[test]
+fn test_two_step_admin_transfer() {
+    let env = Env::default();
+    let (_contract_id, client, admin) = deploy_contract(&env);
+    let new_admin = Address::generate(&env);
+
+    let result = client.try_accept_admin();
+    assert_eq!(result, Err(Ok(Error::NoPendingAdmin.into())));
+
+    client.propose_admin(&new_admin);
+    assert_eq!(client.admin(), admin);
+    assert_eq!(client.pending_admin(), Some(new_admin.clone()));
+
+    client.accept_admin();
+    assert_eq!(client.admin(), new_admin);
+    assert_eq!(client.pending_admin(), None);
+
+    // admin_mint now credits the new admin
+    client.admin_mint(&1_000);
+    assert_eq!(client.balance(&new_admin), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn test_accept_admin_requires_nominee_auth() {
+    let env = Env::default();
+    let (contract_id, client, admin) = deploy_contract(&env);
+    let new_admin = Address::generate(&env);
+
+    client.propose_admin(&new_admin);
+
+    env.mock_auths(&[MockAuth {
+        address: &admin,
+        invoke: &MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "accept_admin",
+            args: ().into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    client.accept_admin();
+}
+
+#[test]
+fn test_minter_role() {
+    let env = Env::default();
+    let (_contract_id, client, _admin) = deploy_contract(&env);
+    let minter = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let result = client.try_mint(&minter, &user, &100);
+    assert_eq!(result, Err(Ok(Error::MinterOnly.into())));
+
+    client.set_minter(&minter, &true);
+    assert!(client.is_minter(&minter));
+    client.mint(&minter, &user, &100);
+    assert_eq!(client.balance(&user), 100);
+    assert_eq!(client.total_supply(), INITIAL_SUPPLY + 100);
+
+    client.set_minter(&minter, &false);
+    assert!(!client.is_minter(&minter));
+    let result = client.try_mint(&minter, &user, &100);
+    assert_eq!(result, Err(Ok(Error::MinterOnly.into())));
+}
+
+#[test]
+fn test_pauser_role() {
+    let env = Env::default();
+    let (_contract_id, client, _admin) = deploy_contract(&env);
+    let pauser = Address::generate(&env);
+
+    let result = client.try_pause(&pauser);
+    assert_eq!(result, Err(Ok(Error::PauserOnly.into())));
+
+    assert!(!client.is_pauser(&pauser));
+    client.set_pauser(&pauser, &true);
+    assert!(client.is_pauser(&pauser));
+
+    client.pause(&pauser);
+    assert!(client.paused());
+    client.unpause(&pauser);
+    assert!(!client.paused());
+}
+
+#[test]
+fn test_frozen_account_cannot_move_funds() {
+    let env = Env::default();
+    let (_contract_id, client, admin) = deploy_contract(&env);
+    let holder = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    client.transfer(&admin, &holder, &1_000);
+    client.approve(&holder, &spender, &1_000, &1_000);
+    client.freeze(&holder);
+    assert!(client.is_frozen(&holder));
+
+    let result = client.try_transfer(&holder, &admin, &1);
+    assert_eq!(result, Err(Ok(Error::AccountFrozen.into())));
+    let result = client.try_transfer(&admin, &holder, &1);
+    assert_eq!(result, Err(Ok(Error::AccountFrozen.into())));
+    let result = client.try_transfer_from(&spender, &holder, &admin, &1);
+    assert_eq!(result, Err(Ok(Error::AccountFrozen.into())));
+    let result = client.try_burn(&holder, &1);
+    assert_eq!(result, Err(Ok(Error::AccountFrozen.into())));
+
+    client.unfreeze(&holder);
+    assert!(!client.is_frozen(&holder));
+    client.transfer(&holder, &admin, &1);
+    assert_eq!(client.balance(&holder), 999);
+}
+
+#[test]
+fn test_frozen_account_cannot_receive_funds() {
+    let env = Env::default();
+    let (_contract_id, client, admin) = deploy_contract(&env);
+    let holder = Address::generate(&env);
+    let frozen = Address::generate(&env);
+
+    client.transfer(&admin, &holder, &1_000);
+    client.freeze(&frozen);
+
+    let result = client.try_mint(&admin, &frozen, &1);
+    assert_eq!(result, Err(Ok(Error::AccountFrozen.into())));
+    let result = client.try_mint_to_user(&frozen);
+    assert_eq!(result, Err(Ok(Error::AccountFrozen.into())));
+    let result = client.try_clawback_to(&holder, &frozen, &1);
+    assert_eq!(result, Err(Ok(Error::AccountFrozen.into())));
+
+    client.freeze(&admin);
+    let result = client.try_admin_mint(&1);
+    assert_eq!(result, Err(Ok(Error::AccountFrozen.into())));
+
+    assert_eq!(client.balance(&frozen), 0);
+    assert_eq!(client.balance(&holder), 1_000);
+    assert_eq!(client.total_supply(), INITIAL_SUPPLY);
+}
+
+#[test]
+fn test_clawback() {
+    let env = Env::default();
+    let (_contract_id, client, admin) = deploy_contract(&env);
+    let holder = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    client.transfer(&admin, &holder, &1_000);
+    client.freeze(&holder);
+
+    client.clawback(&holder, &400);
+    assert_eq!(client.balance(&holder), 600);
+    assert_eq!(client.total_supply(), INITIAL_SUPPLY - 400);
+
+    client.clawback_to(&holder, &treasury, &600);
+    assert_eq!(client.balance(&holder), 0);
+    assert_eq!(client.balance(&treasury), 600);
+    assert_eq!(client.total_supply(), INITIAL_SUPPLY - 400);
+
+    let result = client.try_clawback(&holder, &1);
+    assert_eq!(result, Err(Ok(Error::InsufficientBalance.into())));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn test_unauthorized_freeze() {
+    let env = Env::default();
+    let (_contract_id, client, _admin) = deploy_contract(&env);
+
+    env.set_auths(&[]);
+    client.freeze(&Address::generate(&env));
+}
+
+// Funds `holders` accounts, then meters a single transfer between two fresh ones
+fn transfer_cost(holders: u32) -> Footprint {
+    let env = Env::new_with_config(EnvTestConfig { capture_snapshot_at_drop: false });
+    let (_contract_id, client, admin) = deploy_contract(&env);
+    for _ in 0..holders {
+        let holder = Address::generate(&env);
+        client.transfer(&admin, &holder, &1);
+    }
+
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    client.transfer(&admin, &from, &1_000);
+    client.transfer(&admin, &to, &1_000);
+
+    measure(&env, || client.transfer(&from, &to, &100))
+}
+
+// The ledger footprint of a transfer (entries read/written, bytes written, rent) must not
+// depend on how many accounts hold ATC: it writes both balances and the sender's auth nonce
+#[test]
+fn test_transfer_cost_flat_as_holders_grow() {
+    let cost = transfer_cost(500);
+    assert_eq!(transfer_cost(10), cost);
+    assert_eq!(cost.write_entries, 3);
+}
+
+#[test]
+fn test_max_supply_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = env.register(Attorneycoin, (&admin, Some(INITIAL_SUPPLY + MINT_LIMIT + 1_000)));
+    let client = AttorneycoinClient::new(&env, &contract_id);
+    let minter = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    assert_eq!(client.remaining_mintable(), Some(MINT_LIMIT + 1_000));
+
+    client.admin_mint(&1_000);
+    assert_eq!(client.remaining_mintable(), Some(MINT_LIMIT));
+
+    client.set_minter(&minter, &true);
+    let result = client.try_mint(&minter, &user, &(MINT_LIMIT + 1));
+    assert_eq!(result, Err(Ok(Error::MintingCapped.into())));
+
+    // mint_to_user is capped as well once the faucet amount no longer fits
+    client.set_max_supply(&(INITIAL_SUPPLY + 1_000 + MINT_LIMIT - 1));
+    let result = client.try_mint_to_user(&user);
+    assert_eq!(result, Err(Ok(Error::MintingCapped.into())));
+}
+
+#[test]
+fn test_max_supply_only_lowered() {
+    let env = Env::default();
+    let (_contract_id, client, _admin) = deploy_contract(&env);
+
+    assert_eq!(client.max_supply(), None);
+    assert_eq!(client.remaining_mintable(), None);
+
+    client.set_max_supply(&(INITIAL_SUPPLY * 2));
+    assert_eq!(client.max_supply(), Some(INITIAL_SUPPLY * 2));
+
+    let result = client.try_set_max_supply(&(INITIAL_SUPPLY * 3));
+    assert_eq!(result, Err(Ok(Error::InvalidMaxSupply.into())));
+
+    let result = client.try_set_max_supply(&(INITIAL_SUPPLY - 1));
+    assert_eq!(result, Err(Ok(Error::InvalidMaxSupply.into())));
+
+    client.set_max_supply(&INITIAL_SUPPLY);
+    assert_eq!(client.remaining_mintable(), Some(0));
+}
+
+#[test]
+fn test_faucet_config_and_status() {
+    let env = Env::default();
+    let (_contract_id, client, _admin) = deploy_contract(&env);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    client.set_faucet_config(&FaucetConfig {
+        enabled: true,
+        amount: 500,
+        cooldown: 3_600,
+        daily_budget: None,
+        allowlist_only: false,
+    });
+    assert_eq!(client.faucet_status(&user), FaucetStatus { next_eligible_at: 1_000, claimable: 500 });
+
+    client.mint_to_user(&user);
+    assert_eq!(client.balance(&user), 500);
+    assert_eq!(client.faucet_status(&user), FaucetStatus { next_eligible_at: 4_600, claimable: 0 });
+
+    client.transfer(&user, &other, &200);
+    env.ledger().with_mut(|li| li.timestamp = 4_600);
+    assert_eq!(client.faucet_status(&user), FaucetStatus { next_eligible_at: 4_600, claimable: 200 });
+    client.mint_to_user(&user);
+    assert_eq!(client.balance(&user), 500);
+
+    // A cooldown too long to add to the last claim time means "never again"
+    client.transfer(&user, &other, &200);
+    client.set_faucet_config(&FaucetConfig { cooldown: u64::MAX, ..client.faucet_config() });
+    assert_eq!(client.faucet_status(&user), FaucetStatus { next_eligible_at: u64::MAX, claimable: 0 });
+    let result = client.try_mint_to_user(&user);
+    assert_eq!(result, Err(Ok(Error::DailyMintingReached.into())));
+}
+
+#[test]
+fn test_faucet_disabled_and_allowlist() {
+    let env = Env::default();
+    let (_contract_id, client, _admin) = deploy_contract(&env);
+    let user = Address::generate(&env);
+    let mut config = client.faucet_config();
+
+    config.enabled = false;
+    client.set_faucet_config(&config);
+    let result = client.try_mint_to_user(&user);
+    assert_eq!(result, Err(Ok(Error::FaucetDisabled.into())));
+
+    config.enabled = true;
+    config.allowlist_only = true;
+    client.set_faucet_config(&config);
+    let result = client.try_mint_to_user(&user);
+    assert_eq!(result, Err(Ok(Error::NotAllowlisted.into())));
+
+    client.set_faucet_allowed(&user, &true);
+    client.mint_to_user(&user);
+    assert_eq!(client.balance(&user), MINT_LIMIT);
+}
+
+#[test]
+fn test_faucet_daily_budget() {
+    let env = Env::default();
+    let (_contract_id, client, _admin) = deploy_contract(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    env.ledger().with_mut(|li| li.timestamp = 86400 + 10);
+
+    let mut config = client.faucet_config();
+    config.daily_budget = Some(MINT_LIMIT + MINT_LIMIT / 2);
+    client.set_faucet_config(&config);
+
+    client.mint_to_user(&first);
+    let result = client.try_mint_to_user(&second);
+    assert_eq!(result, Err(Ok(Error::FaucetBudgetExhausted.into())));
+    assert_eq!(client.faucet_status(&second), FaucetStatus { next_eligible_at: 2 * 86400, claimable: 0 });
+
+    env.ledger().with_mut(|li| li.timestamp = 2 * 86400);
+    client.mint_to_user(&second);
+    assert_eq!(client.balance(&second), MINT_LIMIT);
+}
+
+#[test]
+fn test_snapshot_balances() {
+    let env = Env::default();
+    let (_contract_id, client, admin) = deploy_contract(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.transfer(&admin, &alice, &1_000);
+    let first = client.snapshot();
+    assert_eq!(first, 1);
+
+    client.transfer(&alice, &bob, &300);
+    client.burn(&alice, &100);
+    client.transfer(&alice, &bob, &100);
+    let second = client.snapshot();
+
+    client.admin_mint(&5_000);
+    let third = client.snapshot();
+
+    assert_eq!(client.balance_of_at(&alice, &first), 1_000);
+    assert_eq!(client.balance_of_at(&bob, &first), 0);
+    assert_eq!(client.total_supply_at(&first), INITIAL_SUPPLY);
+
+    assert_eq!(client.balance_of_at(&alice, &second), 500);
+    assert_eq!(client.balance_of_at(&bob, &second), 400);
+    assert_eq!(client.total_supply_at(&second), INITIAL_SUPPLY - 100);
+
+    // Untouched since the last snapshot: the current value is the answer
+    assert_eq!(client.balance_of_at(&alice, &third), 500);
+    assert_eq!(client.total_supply_at(&third), INITIAL_SUPPLY + 4_900);
+}
+
+// Takes `snapshots` snapshots with `from` moving funds in each period, then meters one
+// more transfer
+fn snapshot_transfer_cost(snapshots: u32) -> Footprint {
+    let env = Env::new_with_config(EnvTestConfig { capture_snapshot_at_drop: false });
+    let (_contract_id, client, admin) = deploy_contract(&env);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    client.transfer(&admin, &from, &1_000_000);
+
+    for _ in 0..snapshots {
+        client.snapshot();
+        client.transfer(&from, &to, &1);
+    }
+
+    client.snapshot();
+    measure(&env, || client.transfer(&from, &to, &1))
+}
+
+// Each checkpoint is its own entry, so the footprint of a transfer does not grow with the
+// number of snapshots an account has been through. Besides the plain transfer's 3 writes,
+// each balance gets a checkpoint and a new checkpoint count.
+#[test]
+fn test_transfer_cost_flat_as_snapshots_grow() {
+    let cost = snapshot_transfer_cost(400);
+    assert_eq!(snapshot_transfer_cost(1), cost);
+    assert_eq!(cost.write_entries, 7);
+}
+
+#[test]
+fn test_snapshot_lookup_many_checkpoints() {
+    let env = Env::new_with_config(EnvTestConfig { capture_snapshot_at_drop: false });
+    let (_contract_id, client, admin) = deploy_contract(&env);
+    let alice = Address::generate(&env);
+
+    // Alice receives 10 in every odd period and is untouched in even ones
+    for id in 1..=100u32 {
+        client.snapshot();
+        if id % 2 == 1 {
+            client.transfer(&admin, &alice, &10);
+        }
+    }
+
+    for id in 1..=100u32 {
+        let expected = ((id as i128) / 2) * 10;
+        assert_eq!(client.balance_of_at(&alice, &id), expected);
+        assert_eq!(client.total_supply_at(&id), INITIAL_SUPPLY);
+    }
+    assert_eq!(client.balance(&alice), 500);
+}
+
+#[test]
+fn test_snapshot_invalid_id() {
+    let env = Env::default();
+    let (_contract_id, client, admin) = deploy_contract(&env);
+
+    let result = client.try_balance_of_at(&admin, &1);
+    assert_eq!(result, Err(Ok(Error::InvalidSnapshot.into())));
+
+    env.ledger().with_mut(|li| li.sequence_number = 42);
+    let snapshot_id = client.snapshot();
+    assert_eq!(client.snapshot_info(&snapshot_id).ledger, 42);
+
+    let result = client.try_total_supply_at(&0);
+    assert_eq!(result, Err(Ok(Error::InvalidSnapshot.into())));
+    let result = client.try_total_supply_at(&2);
+    assert_eq!(result, Err(Ok(Error::InvalidSnapshot.into())));
+}
+
+#[test]
+fn test_paused_blocks_state_changes() {
+    let env = Env::default();
+    let (_contract_id, client, admin) = deploy_contract(&env);
+    let user = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    client.approve(&admin, &spender, &100, &1_000);
+    client.pause(&admin);
+
+    let paused = Err(Ok(Error::Paused.into()));
+    assert_eq!(client.try_transfer(&admin, &user, &1), paused);
+    assert_eq!(client.try_transfer_from(&spender, &admin, &user, &1), paused);
+    assert_eq!(client.try_approve(&admin, &spender, &1, &1_000), paused);
+    assert_eq!(client.try_burn(&admin, &1), paused);
+    assert_eq!(client.try_burn_from(&spender, &admin, &1), paused);
+    assert_eq!(client.try_mint_to_user(&user), paused);
+    assert_eq!(client.try_admin_mint(&1), paused);
+    assert_eq!(client.try_mint(&admin, &user, &1), paused);
+    assert_eq!(client.try_clawback(&admin, &1), paused);
+    assert_eq!(client.try_clawback_to(&admin, &user, &1), paused);
+
+    // Admin controls stay available during an incident
+    client.freeze(&user);
+
+    client.unpause(&admin);
+    client.transfer(&admin, &spender, &1);
+    assert_eq!(client.balance(&spender), 1);
+}
+
+#[test]
+fn test_batch_transfer() {
+    let env = Env::default();
+    let (_contract_id, client, admin) = deploy_contract(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.batch_transfer(&admin, &vec![&env, (alice.clone(), 100), (bob.clone(), 250), (alice.clone(), 50)]);
+    assert_eq!(env.events().all().len(), 3);
+    assert_eq!(client.balance(&alice), 150);
+    assert_eq!(client.balance(&bob), 250);
+    assert_eq!(client.balance(&admin), INITIAL_SUPPLY - 400);
+}
+
+#[test]
+fn test_batch_transfer_is_atomic() {
+    let env = Env::default();
+    let (_contract_id, client, admin) = deploy_contract(&env);
+    let holder = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.transfer(&admin, &holder, &300);
+
+    let result = client.try_batch_transfer(&holder, &vec![&env, (alice.clone(), 200), (bob.clone(), 101)]);
+    assert_eq!(result, Err(Ok(Error::InsufficientBalance.into())));
+
+    let result = client.try_batch_transfer(&holder, &vec![&env, (alice.clone(), i128::MAX), (bob.clone(), 1)]);
+    assert_eq!(result, Err(Ok(Error::AmountOverflow.into())));
+
+    client.freeze(&bob);
+    let result = client.try_batch_transfer(&holder, &vec![&env, (alice.clone(), 1), (bob.clone(), 1)]);
+    assert_eq!(result, Err(Ok(Error::AccountFrozen.into())));
+
+    assert_eq!(client.balance(&holder), 300);
+    assert_eq!(client.balance(&alice), 0);
+}
+
+#[test]
+fn test_batch_transfer_max_size_fits_budget() {
+    let env = Env::new_with_config(EnvTestConfig { capture_snapshot_at_drop: false });
+    let (_contract_id, client, admin) = deploy_contract(&env);
+
+    let mut transfers = Vec::new(&env);
+    for _ in 0..MAX_BATCH_SIZE {
+        transfers.push_back((Address::generate(&env), 1_000));
+    }
+    // Worst case: an active snapshot makes every leg also write a checkpoint
+    client.snapshot();
+    measure(&env, || client.batch_transfer(&admin, &transfers));
+
+    transfers.push_back((Address::generate(&env), 1_000));
+    let result = client.try_batch_transfer(&admin, &transfers);
+    assert_eq!(result, Err(Ok(Error::BatchTooLarge.into())));
+}
@@ -1,44 +1,312 @@
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String};
+use soroban_sdk::{contract, contractimpl, contracttype, panic_with_error, Address, BytesN, Env, IntoVal, String, Symbol, TryFromVal, Val, Vec};
+use crate::{
+    BreachReportedEvent, DocumentAnchoredEvent, EarlyMaturityEvent, Error as BondStorageError, PrincipalPaidEvent,
+    GuaranteeUpdatedEvent, RatingChangedEvent, StatusChangedEvent,
+};
+use crate::validation::{is_valid_cnpj, is_valid_isin, is_valid_lei};
+pub use debenture_types::{Bond, BondStatus, DayCount, Indexer, IssueData, Remuneration, RATE_SCALE};
+
+// Maximum number of ISINs returned by one `list_series` page
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+// Longest amortization table accepted (monthly payments over 20 years)
+pub const MAX_SCHEDULE_LEN: u32 = 240;
+
+// Most collateral entries a guarantee can list
+pub const MAX_COLLATERAL_LEN: u32 = 20;
+
+// Most guarantors (fiadores or avalistas) a guarantee can list
+pub const MAX_GUARANTORS: u32 = 20;
+
+// Most covenants a series can carry; they come from the indenture, so the list does not
+// grow over the life of the series
+pub const MAX_COVENANTS: u32 = 50;
+
+// TTL policy (~5s ledgers). A series can go months without being touched between coupon
+// dates, so its entries are bumped for four months on every read or write.
+pub const DAY_IN_LEDGERS: u32 = 17280;
+pub const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+pub const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+pub const SERIES_BUMP_AMOUNT: u32 = 120 * DAY_IN_LEDGERS;
+pub const SERIES_LIFETIME_THRESHOLD: u32 = SERIES_BUMP_AMOUNT - 30 * DAY_IN_LEDGERS;
 
 // -------------------------
 //  Structs definition
  // -------------------------
 
 #[contracttype]
-#[derive(Clone)]
-pub struct Bond {
-    pub currency: Address,
-    pub denomination: i128,
-    pub interest_rate: i128,
-    pub frequency: u64,
-    pub issue_number: u64,
-    pub issue_date: u64,
-    pub maturity_date: u64,
-    pub bond_status: BondStatus,
-}
-
-#[contracttype]
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Issuer {
     pub institution: Address,
     pub cnpj: String,
     pub lei: String,
 }
 
+// One principal repayment: `percentage` of the original principal (scaled by RATE_SCALE)
+// is due on `date`
 #[contracttype]
-#[derive(Clone)]
-pub struct IssueData {
-    pub investor: Address,
-    pub principal: u64,
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Amortization {
+    pub date: u64,
+    pub percentage: i128,
+}
+
+// How the manager describes an amortization table to `set_amortization`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AmortizationPlan {
+    // All principal at maturity
+    Bullet,
+    // SAC: equal shares on each date, the rounding remainder going to the last one
+    Linear(Vec<u64>),
+    Custom(Vec<Amortization>),
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AmortizationKind {
+    Bullet,
+    Linear,
+    Custom,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AmortizationSchedule {
+    pub kind: AmortizationKind,
+    pub payments: Vec<Amortization>,
+}
+
+// Whether the covenant caps the metric (e.g. net debt / EBITDA <= 3.5) or sets a floor
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CovenantBound {
+    Max,
+    Min,
+}
+
+// Financial covenant from the indenture. `threshold` uses RATE_SCALE; a breach of an
+// `automatic` covenant accelerates the debt at once (vencimento antecipado automático),
+// otherwise it waits for the trustee to act on the bondholders' decision.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Covenant {
+    pub description_hash: BytesN<32>,
+    pub metric: Symbol,
+    pub bound: CovenantBound,
+    pub threshold: i128,
+    pub automatic: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BreachStatus {
+    Pending,
+    Waived,
+    Accelerated,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Breach {
+    pub covenant_id: u32,
+    pub observed: i128,
+    pub reported_at: u64,
+    pub status: BreachStatus,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RatingScale {
+    National,
+    Global,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Outlook {
+    Positive,
+    Stable,
+    Negative,
+    Developing,
+}
+
+// A rating action published by an agency on `date`. `grade` is the agency's own notation
+// (e.g. "brAA+") and `notch` its position on that scale, 1 being the best, so that
+// actions of the same agency can be compared.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rating {
+    pub agency: Address,
+    pub scale: RatingScale,
+    pub grade: String,
+    pub notch: u32,
+    pub outlook: Outlook,
+    pub date: u64,
+    pub report_hash: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RatingAction {
+    Upgrade,
+    Downgrade,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GuaranteeType {
+    // Unsecured: no collateral backs the series
+    Quirografaria,
+    // Garantia real: specific assets are pledged or mortgaged
+    Real,
+    Flutuante,
+    Subordinada,
+}
+
+// Where a collateral asset lives: a token contract on the network, or the SHA-256 of the
+// off-chain record (matrícula, contrato de cessão...)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CollateralRef {
+    OnChain(Address),
+    OffChain(BytesN<32>),
+}
+
+// `value` is the appraised value in the bond currency
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Collateral {
+    pub asset: CollateralRef,
+    pub amount: i128,
+    pub value: i128,
+}
+
+// Guarantee package of a series. `coverage_ratio` is collateral value over outstanding
+// principal as assessed by the trustee, scaled by RATE_SCALE.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Guarantee {
+    pub guarantee_type: GuaranteeType,
+    pub collateral: Vec<Collateral>,
+    pub coverage_ratio: i128,
+    pub guarantors: Vec<Address>,
+    pub updated_at: u64,
+}
+
+// Principal placed when the offering closed and how much of it has been paid back
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Principal {
+    pub placed: i128,
+    pub paid: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DocumentType {
+    // Escritura de emissão; exactly one per series, anchored before any amendment
+    Indenture,
+    // Aditamento à escritura
+    Amendment,
+    Other,
+}
+
+// Legal document anchored on-chain: the SHA-256 of the file and where to fetch it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Document {
+    pub doc_type: DocumentType,
+    pub hash: BytesN<32>,
+    pub uri: String,
+    pub effective_date: u64,
+    pub anchored_at: u64,
+}
+
+// One entry of a series' history: bond and issuer data as they stood after a change,
+// with when it happened and who authorized it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TermsVersion {
+    pub version: u32,
+    pub bond: Bond,
+    pub issuer: Issuer,
+    pub ledger: u32,
+    pub timestamp: u64,
+    pub author: Address,
 }
 
+// Registry-wide data (Manager, SeriesCount) lives in instance storage; everything keyed
+// by ISIN lives in persistent entries. Lists that grow over the life of a series keep one
+// entry per item plus a count.
 #[contracttype]
-#[derive(Clone, Copy)]
-pub enum BondStatus {
-    Offered,
-    Issued,
-    Matured,
-    Redeemed,
+#[derive(Clone)]
+pub enum DataKey {
+    Manager,
+    SeriesCount,
+    SeriesAt(u32),
+    SeriesManager(String),
+    Bond(String),
+    Issuer(String),
+    Principal(String),
+    DocumentCount(String),
+    Document(String, u32),
+    DocumentHash(String, BytesN<32>),
+    Indenture(String),
+    Amortization(String),
+    Trustee(String),
+    Covenants(String),
+    BreachCount(String),
+    Breach(String, u32),
+    Guarantee(String),
+    RatingAgency(Address),
+    RatingCount(String),
+    Rating(String, u32),
+    LatestRating(String, Address, RatingScale),
+    VersionCount(String),
+    Version(String, u32),
+}
+
+// -------------------------
+//  Internal helpers
+// -------------------------
+
+fn extend_instance_ttl(env: &Env) {
+    env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+// Reads a persistent entry, bumping its TTL when present
+fn read_persistent<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+    let value = env.storage().persistent().get(key);
+    if value.is_some() {
+        env.storage().persistent().extend_ttl(key, SERIES_LIFETIME_THRESHOLD, SERIES_BUMP_AMOUNT);
+    }
+    value
+}
+
+fn write_persistent<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    env.storage().persistent().extend_ttl(key, SERIES_LIFETIME_THRESHOLD, SERIES_BUMP_AMOUNT);
+}
+
+// Appends an item to a per-series list stored as `item_key(index)` entries and a count,
+// returning its index
+fn push_item<V: IntoVal<Env, Val>>(env: &Env, count_key: &DataKey, item_key: impl Fn(u32) -> DataKey, item: &V) -> u32 {
+    let index: u32 = read_persistent(env, count_key).unwrap_or(0);
+    write_persistent(env, &item_key(index), item);
+    write_persistent(env, count_key, &(index + 1));
+    index
+}
+
+// Returns up to `limit` items (capped at MAX_PAGE_SIZE) of a list of `count` persistent
+// `item_key(index)` entries, oldest first
+fn read_page<V: IntoVal<Env, Val> + TryFromVal<Env, Val>>(env: &Env, count: u32, item_key: impl Fn(u32) -> DataKey, start: u32, limit: u32) -> Vec<V> {
+    let end = count.min(start.saturating_add(limit.min(MAX_PAGE_SIZE)));
+    let mut page = Vec::new(env);
+    for index in start..end {
+        page.push_back(read_persistent(env, &item_key(index)).unwrap());
+    }
+    page
 }
 
 // -------------------------
@@ -50,89 +318,631 @@ pub struct BondStorage;
 
 #[contractimpl]
 impl BondStorage {
-    // Inicialização
-    pub fn initialize(env: Env, manager: Address) {
-        env.storage().instance().set(&"manager", &manager);
-        env.storage().instance().set(&"bondISIN", &"BREMISDEB5H2");
-        env.storage().instance().set(&"issueNumber", &1u64);
-        env.storage().instance().set(&"bondStatus", &BondStatus::Offered);
+    // Inicialização: the manager administers the registry and registers new series. Set at
+    // deploy time, so nobody can claim a fresh deployment before its owner
+    pub fn __constructor(env: Env, manager: Address) {
+        env.storage().instance().set(&DataKey::Manager, &manager);
+        extend_instance_ttl(&env);
     }
 
-    // Only manager can be change
-    fn require_manager(env: &Env) {
-        let manager: Address = env.storage().instance().get(&"manager").unwrap();
+    // Only the registry manager can register series or reassign their managers
+    fn require_manager(env: &Env) -> Address {
+        let manager: Address = env.storage().instance().get(&DataKey::Manager).unwrap();
         manager.require_auth();
+        extend_instance_ttl(env);
+        manager
+    }
+
+    // Only the manager of a series can change its data
+    fn require_series_manager(env: &Env, isin: &String) -> Address {
+        let manager: Address = read_persistent(env, &DataKey::SeriesManager(isin.clone()))
+            .unwrap_or_else(|| panic_with_error!(env, BondStorageError::SeriesNotFound));
+        manager.require_auth();
+        extend_instance_ttl(env);
+        manager
+    }
+
+    // Appends the current bond and issuer data of a series to its history
+    fn record_version(env: &Env, isin: &String, author: Address) {
+        let version: u32 = read_persistent(env, &DataKey::VersionCount(isin.clone())).unwrap_or(0);
+        let entry = TermsVersion {
+            version,
+            bond: Self::bond_info(env.clone(), isin.clone()),
+            issuer: Self::issuer_info(env.clone(), isin.clone()),
+            ledger: env.ledger().sequence(),
+            timestamp: env.ledger().timestamp(),
+            author,
+        };
+        write_persistent(env, &DataKey::Version(isin.clone(), version), &entry);
+        write_persistent(env, &DataKey::VersionCount(isin.clone()), &(version + 1));
+    }
+
+    // Rejects issuer data whose CNPJ or LEI fail their check digits
+    fn validate_issuer(env: &Env, issuer: &Issuer) {
+        if !is_valid_cnpj(&issuer.cnpj) {
+            panic_with_error!(env, BondStorageError::InvalidCnpj);
+        }
+        if !is_valid_lei(&issuer.lei) {
+            panic_with_error!(env, BondStorageError::InvalidLei);
+        }
+    }
+
+    // A fixed-rate bond has no index share and a positive rate; a floating one must follow
+    // a positive share of its index. Spreads are never negative.
+    fn validate_remuneration(env: &Env, remuneration: &Remuneration) {
+        let valid = remuneration.spread >= 0 && match remuneration.indexer {
+            Indexer::Prefixado => remuneration.index_percentage == 0 && remuneration.spread > 0,
+            Indexer::Cdi | Indexer::Ipca => remuneration.index_percentage > 0,
+        };
+        if !valid {
+            panic_with_error!(env, BondStorageError::InvalidRemuneration);
+        }
+    }
+
+    // Only the trustee (agente fiduciário) of a series can report or act on breaches
+    fn require_trustee(env: &Env, isin: &String) -> Address {
+        let trustee = Self::trustee(env.clone(), isin.clone())
+            .unwrap_or_else(|| panic_with_error!(env, BondStorageError::TrusteeNotSet));
+        trustee.require_auth();
+        extend_instance_ttl(env);
+        trustee
+    }
+
+    fn read_pending_breach(env: &Env, isin: &String, breach_id: u32) -> Breach {
+        let breach = Self::breach(env.clone(), isin.clone(), breach_id);
+        if breach.status != BreachStatus::Pending {
+            panic_with_error!(env, BondStorageError::BreachNotPending);
+        }
+        breach
+    }
+
+    // Issued -> Matured ahead of the maturity date, settling the breach that caused it
+    fn accelerate(env: &Env, isin: &String, trustee: Address, breach_id: u32) {
+        Self::transition(env, isin, trustee, BondStatus::Issued, BondStatus::Matured);
+        EarlyMaturityEvent { isin: isin.clone(), breach_id }.publish(env);
+    }
+
+    // Moves a series from `from` to `to`, rejecting the call if it is elsewhere
+    fn transition(env: &Env, isin: &String, author: Address, from: BondStatus, to: BondStatus) {
+        let mut bond = Self::bond_info(env.clone(), isin.clone());
+        if bond.bond_status != from {
+            panic_with_error!(env, BondStorageError::InvalidStatusTransition);
+        }
+        bond.bond_status = to;
+        write_persistent(env, &DataKey::Bond(isin.clone()), &bond);
+        Self::record_version(env, isin, author);
+        StatusChangedEvent { isin: isin.clone(), from, to }.publish(env);
     }
 
     // ------------------------
     //   SET Functions
     // ------------------------
 
-    pub fn set_bond(env: Env, bond: Bond) {
+    // Registers a new debenture series; the registry manager starts as its manager
+    pub fn register_series(env: Env, isin: String, bond: Bond, issuer: Issuer) {
+        let manager = Self::require_manager(&env);
+        if !is_valid_isin(&isin) {
+            panic_with_error!(&env, BondStorageError::InvalidIsin);
+        }
+        Self::validate_issuer(&env, &issuer);
+        Self::validate_remuneration(&env, &bond.remuneration);
+        if env.storage().persistent().has(&DataKey::SeriesManager(isin.clone())) {
+            panic_with_error!(&env, BondStorageError::SeriesAlreadyRegistered);
+        }
+
+        let count: u32 = env.storage().instance().get(&DataKey::SeriesCount).unwrap_or(0);
+        write_persistent(&env, &DataKey::SeriesAt(count), &isin);
+        env.storage().instance().set(&DataKey::SeriesCount, &(count + 1));
+
+        // Every series starts its lifecycle as Registered
+        let bond = Bond { bond_status: BondStatus::Registered, ..bond };
+        write_persistent(&env, &DataKey::SeriesManager(isin.clone()), &manager);
+        write_persistent(&env, &DataKey::Bond(isin.clone()), &bond);
+        write_persistent(&env, &DataKey::Issuer(isin.clone()), &issuer);
+        Self::record_version(&env, &isin, manager);
+    }
+
+    // Hands a series over to another manager (e.g. the trustee of that emission)
+    pub fn set_series_manager(env: Env, isin: String, manager: Address) {
         Self::require_manager(&env);
-        let isin: String = env.storage().instance().get(&"bondISIN").unwrap();
-        env.storage().instance().set(&(isin.clone(), "bond"), &bond);
+        if !env.storage().persistent().has(&DataKey::SeriesManager(isin.clone())) {
+            panic_with_error!(&env, BondStorageError::SeriesNotFound);
+        }
+        write_persistent(&env, &DataKey::SeriesManager(isin), &manager);
+    }
+
+    pub fn set_bond(env: Env, isin: String, bond: Bond) {
+        let manager = Self::require_series_manager(&env, &isin);
+        Self::validate_remuneration(&env, &bond.remuneration);
+        let previous = Self::bond_info(env.clone(), isin.clone());
+        // The amortization table must end on the maturity date, so a new maturity drops it and
+        // the manager sets it again. Its percentages are relative, so the denomination is free
+        if bond.maturity_date != previous.maturity_date {
+            env.storage().persistent().remove(&DataKey::Amortization(isin.clone()));
+        }
+        let bond_status = previous.bond_status;
+        write_persistent(&env, &DataKey::Bond(isin.clone()), &Bond { bond_status, ..bond });
+        Self::record_version(&env, &isin, manager);
+    }
+
+    pub fn set_issuer(env: Env, isin: String, issuer: Issuer) {
+        let manager = Self::require_series_manager(&env, &isin);
+        Self::validate_issuer(&env, &issuer);
+        write_persistent(&env, &DataKey::Issuer(isin.clone()), &issuer);
+        Self::record_version(&env, &isin, manager);
+    }
+
+    // Appends a document to the series' list; anchored documents are never rewritten.
+    // Anchoring is only allowed for the series manager
+    pub fn anchor_document(
+        env: Env,
+        isin: String,
+        doc_type: DocumentType,
+        hash: BytesN<32>,
+        uri: String,
+        effective_date: u64,
+    ) -> u32 {
+        Self::require_series_manager(&env, &isin);
+
+        let has_indenture = env.storage().persistent().has(&DataKey::Indenture(isin.clone()));
+        match doc_type {
+            DocumentType::Indenture if has_indenture => {
+                panic_with_error!(&env, BondStorageError::IndentureAlreadyAnchored)
+            }
+            DocumentType::Amendment if !has_indenture => {
+                panic_with_error!(&env, BondStorageError::MissingIndenture)
+            }
+            _ => {}
+        }
+        let hash_key = DataKey::DocumentHash(isin.clone(), hash.clone());
+        if env.storage().persistent().has(&hash_key) {
+            panic_with_error!(&env, BondStorageError::DuplicateDocument);
+        }
+
+        let document = Document {
+            doc_type,
+            hash: hash.clone(),
+            uri,
+            effective_date,
+            anchored_at: env.ledger().timestamp(),
+        };
+        let index = push_item(&env, &DataKey::DocumentCount(isin.clone()), |i| DataKey::Document(isin.clone(), i), &document);
+        write_persistent(&env, &hash_key, &index);
+        if doc_type == DocumentType::Indenture {
+            write_persistent(&env, &DataKey::Indenture(isin.clone()), &index);
+        }
+
+        DocumentAnchoredEvent { isin, index, doc_type, hash }.publish(&env);
+        index
+    }
+
+    // Replaces the amortization table of a series. Dates must be increasing and end on the
+    // maturity date, and the percentages must add up to exactly 100%
+    pub fn set_amortization(env: Env, isin: String, plan: AmortizationPlan) {
+        Self::require_series_manager(&env, &isin);
+        let maturity_date = Self::maturity_date(env.clone(), isin.clone());
+
+        let (kind, payments) = match plan {
+            AmortizationPlan::Bullet => (
+                AmortizationKind::Bullet,
+                Vec::from_array(&env, [Amortization { date: maturity_date, percentage: RATE_SCALE }]),
+            ),
+            AmortizationPlan::Linear(dates) => {
+                if dates.is_empty() {
+                    panic_with_error!(&env, BondStorageError::InvalidSchedule);
+                }
+                let share = RATE_SCALE / dates.len() as i128;
+                let mut payments = Vec::new(&env);
+                for (index, date) in dates.iter().enumerate() {
+                    let percentage = if index as u32 == dates.len() - 1 {
+                        RATE_SCALE - share * (dates.len() as i128 - 1)
+                    } else {
+                        share
+                    };
+                    payments.push_back(Amortization { date, percentage });
+                }
+                (AmortizationKind::Linear, payments)
+            }
+            AmortizationPlan::Custom(payments) => (AmortizationKind::Custom, payments),
+        };
+
+        if payments.is_empty() || payments.len() > MAX_SCHEDULE_LEN {
+            panic_with_error!(&env, BondStorageError::InvalidSchedule);
+        }
+        let mut total: i128 = 0;
+        let mut previous_date: Option<u64> = None;
+        for payment in payments.iter() {
+            if payment.percentage <= 0 || previous_date.is_some_and(|date| payment.date <= date) {
+                panic_with_error!(&env, BondStorageError::InvalidSchedule);
+            }
+            total += payment.percentage;
+            previous_date = Some(payment.date);
+        }
+        if total != RATE_SCALE || previous_date != Some(maturity_date) {
+            panic_with_error!(&env, BondStorageError::InvalidSchedule);
+        }
+
+        write_persistent(&env, &DataKey::Amortization(isin), &AmortizationSchedule { kind, payments });
+    }
+
+    // ------------------------
+    //   Lifecycle
+    // ------------------------
+
+    // Registered -> Offered: subscriptions (token_minting) are accepted from now on
+    pub fn open_offering(env: Env, isin: String) {
+        let manager = Self::require_series_manager(&env, &isin);
+        Self::transition(&env, &isin, manager, BondStatus::Registered, BondStatus::Offered);
+    }
+
+    // Offered -> Issued, recording the principal placed with investors
+    pub fn close_offering(env: Env, isin: String, placed_principal: i128) {
+        let manager = Self::require_series_manager(&env, &isin);
+        if placed_principal < 0 {
+            panic_with_error!(&env, BondStorageError::InvalidAmount);
+        }
+        Self::transition(&env, &isin, manager, BondStatus::Offered, BondStatus::Issued);
+        write_persistent(&env, &DataKey::Principal(isin), &Principal { placed: placed_principal, paid: 0 });
+    }
+
+    // Records principal paid back to investors (amortizations or the final payment)
+    pub fn record_principal_payment(env: Env, isin: String, amount: i128) {
+        Self::require_series_manager(&env, &isin);
+        let status = Self::bond_status(env.clone(), isin.clone());
+        if status != BondStatus::Issued && status != BondStatus::Matured {
+            panic_with_error!(&env, BondStorageError::InvalidStatusTransition);
+        }
+
+        let mut principal = Self::principal(env.clone(), isin.clone());
+        if amount <= 0 || principal.paid + amount > principal.placed {
+            panic_with_error!(&env, BondStorageError::InvalidAmount);
+        }
+        principal.paid += amount;
+        write_persistent(&env, &DataKey::Principal(isin.clone()), &principal);
+        PrincipalPaidEvent { isin, amount, total_paid: principal.paid }.publish(&env);
+    }
+
+    // Issued -> Matured, only once the maturity date has been reached
+    pub fn mark_matured(env: Env, isin: String) {
+        let manager = Self::require_series_manager(&env, &isin);
+        if env.ledger().timestamp() < Self::maturity_date(env.clone(), isin.clone()) {
+            panic_with_error!(&env, BondStorageError::NotYetMatured);
+        }
+        Self::transition(&env, &isin, manager, BondStatus::Issued, BondStatus::Matured);
+    }
+
+    // Matured -> Redeemed, only once all placed principal has been paid
+    pub fn mark_redeemed(env: Env, isin: String) {
+        let manager = Self::require_series_manager(&env, &isin);
+        let principal = Self::principal(env.clone(), isin.clone());
+        if principal.paid < principal.placed {
+            panic_with_error!(&env, BondStorageError::PrincipalOutstanding);
+        }
+        Self::transition(&env, &isin, manager, BondStatus::Matured, BondStatus::Redeemed);
+    }
+
+    // ------------------------
+    //   Covenants
+    // ------------------------
+
+    pub fn set_trustee(env: Env, isin: String, trustee: Address) {
+        Self::require_series_manager(&env, &isin);
+        write_persistent(&env, &DataKey::Trustee(isin), &trustee);
+    }
+
+    // Adds a covenant to the series, returning its id
+    pub fn add_covenant(env: Env, isin: String, covenant: Covenant) -> u32 {
+        Self::require_series_manager(&env, &isin);
+        let mut covenants = Self::covenants(env.clone(), isin.clone());
+        if covenants.len() >= MAX_COVENANTS {
+            panic_with_error!(&env, BondStorageError::TooManyCovenants);
+        }
+        covenants.push_back(covenant);
+        write_persistent(&env, &DataKey::Covenants(isin), &covenants);
+        covenants.len() - 1
+    }
+
+    // Records a breach observed by the trustee while the debt is outstanding, returning
+    // its id. Breaching an automatic covenant matures the series immediately.
+    pub fn report_breach(env: Env, isin: String, covenant_id: u32, observed: i128) -> u32 {
+        let trustee = Self::require_trustee(&env, &isin);
+        if Self::bond_status(env.clone(), isin.clone()) != BondStatus::Issued {
+            panic_with_error!(&env, BondStorageError::InvalidStatusTransition);
+        }
+        let covenant = Self::covenants(env.clone(), isin.clone()).get(covenant_id)
+            .unwrap_or_else(|| panic_with_error!(&env, BondStorageError::CovenantNotFound));
+        let breached = match covenant.bound {
+            CovenantBound::Max => observed > covenant.threshold,
+            CovenantBound::Min => observed < covenant.threshold,
+        };
+        if !breached {
+            panic_with_error!(&env, BondStorageError::NotABreach);
+        }
+
+        let status = if covenant.automatic { BreachStatus::Accelerated } else { BreachStatus::Pending };
+        let breach = Breach { covenant_id, observed, reported_at: env.ledger().timestamp(), status };
+        let breach_id = push_item(&env, &DataKey::BreachCount(isin.clone()), |i| DataKey::Breach(isin.clone(), i), &breach);
+        BreachReportedEvent { isin: isin.clone(), breach_id, covenant_id, observed }.publish(&env);
+
+        if covenant.automatic {
+            Self::accelerate(&env, &isin, trustee, breach_id);
+        }
+        breach_id
+    }
+
+    // Non-automatic breach: the bondholders' assembly decided to accelerate the debt
+    pub fn declare_early_maturity(env: Env, isin: String, breach_id: u32) {
+        let trustee = Self::require_trustee(&env, &isin);
+        let mut breach = Self::read_pending_breach(&env, &isin, breach_id);
+        breach.status = BreachStatus::Accelerated;
+        write_persistent(&env, &DataKey::Breach(isin.clone(), breach_id), &breach);
+        Self::accelerate(&env, &isin, trustee, breach_id);
+    }
+
+    // Non-automatic breach: the bondholders' assembly waived it
+    pub fn waive_breach(env: Env, isin: String, breach_id: u32) {
+        Self::require_trustee(&env, &isin);
+        let mut breach = Self::read_pending_breach(&env, &isin, breach_id);
+        breach.status = BreachStatus::Waived;
+        write_persistent(&env, &DataKey::Breach(isin, breach_id), &breach);
+    }
+
+    // ------------------------
+    //   Guarantees
+    // ------------------------
+
+    // Replaces the guarantee package of a series. Only the trustee, who monitors the
+    // collateral, can update it
+    pub fn set_guarantee(
+        env: Env,
+        isin: String,
+        guarantee_type: GuaranteeType,
+        collateral: Vec<Collateral>,
+        coverage_ratio: i128,
+        guarantors: Vec<Address>,
+    ) {
+        Self::require_trustee(&env, &isin);
+
+        let valid = coverage_ratio >= 0
+            && collateral.len() <= MAX_COLLATERAL_LEN
+            && guarantors.len() <= MAX_GUARANTORS
+            && collateral.iter().all(|item| item.amount > 0 && item.value >= 0)
+            && match guarantee_type {
+                GuaranteeType::Quirografaria => collateral.is_empty(),
+                GuaranteeType::Real => !collateral.is_empty(),
+                GuaranteeType::Flutuante | GuaranteeType::Subordinada => true,
+            };
+        if !valid {
+            panic_with_error!(&env, BondStorageError::InvalidGuarantee);
+        }
+
+        let guarantee = Guarantee {
+            guarantee_type,
+            collateral,
+            coverage_ratio,
+            guarantors,
+            updated_at: env.ledger().timestamp(),
+        };
+        write_persistent(&env, &DataKey::Guarantee(isin.clone()), &guarantee);
+        GuaranteeUpdatedEvent { isin, guarantee_type, coverage_ratio }.publish(&env);
     }
 
-    pub fn set_issuer(env: Env, issuer: Issuer) {
+    // ------------------------
+    //   Ratings
+    // ------------------------
+
+    // Allows or revokes an agency's permission to publish ratings on any series
+    pub fn set_rating_agency(env: Env, agency: Address, enabled: bool) {
         Self::require_manager(&env);
-        let isin: String = env.storage().instance().get(&"bondISIN").unwrap();
-        env.storage().instance().set(&(isin.clone(), "issuer"), &issuer);
+        let key = DataKey::RatingAgency(agency);
+        if enabled {
+            write_persistent(&env, &key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    // Appends a rating to the series' history. Changing grade against the same agency's
+    // previous rating on the same scale publishes an upgrade or downgrade event.
+    pub fn publish_rating(env: Env, isin: String, rating: Rating) {
+        rating.agency.require_auth();
+        extend_instance_ttl(&env);
+        if !Self::is_rating_agency(env.clone(), rating.agency.clone()) {
+            panic_with_error!(&env, BondStorageError::NotRatingAgency);
+        }
+        if !env.storage().persistent().has(&DataKey::SeriesManager(isin.clone())) {
+            panic_with_error!(&env, BondStorageError::SeriesNotFound);
+        }
+        if rating.notch == 0 || rating.grade.is_empty() {
+            panic_with_error!(&env, BondStorageError::InvalidRating);
+        }
+
+        let latest_key = DataKey::LatestRating(isin.clone(), rating.agency.clone(), rating.scale);
+        let previous: Option<Rating> = read_persistent::<u32>(&env, &latest_key)
+            .map(|index| read_persistent(&env, &DataKey::Rating(isin.clone(), index)).unwrap());
+        if let Some(previous) = previous {
+            let action = if rating.notch < previous.notch {
+                Some(RatingAction::Upgrade)
+            } else if rating.notch > previous.notch {
+                Some(RatingAction::Downgrade)
+            } else {
+                None
+            };
+            if let Some(action) = action {
+                RatingChangedEvent {
+                    isin: isin.clone(),
+                    agency: rating.agency.clone(),
+                    action,
+                    previous_grade: previous.grade,
+                    grade: rating.grade.clone(),
+                }
+                .publish(&env);
+            }
+        }
+
+        let index = push_item(&env, &DataKey::RatingCount(isin.clone()), |i| DataKey::Rating(isin.clone(), i), &rating);
+        write_persistent(&env, &latest_key, &index);
     }
 
     // ------------------------
     //   GET Functions
     // ------------------------
 
-    pub fn institution(env: Env) -> Address {
-        let isin: String = env.storage().instance().get(&"bondISIN").unwrap();
-        let issuer: Issuer = env.storage().instance().get(&(isin, "issuer")).unwrap();
-        issuer.institution
+    pub fn series_count(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::SeriesCount).unwrap_or(0)
+    }
+
+    // Returns up to `limit` ISINs (capped at MAX_PAGE_SIZE) in registration order
+    pub fn list_series(env: Env, start: u32, limit: u32) -> Vec<String> {
+        read_page(&env, Self::series_count(env.clone()), DataKey::SeriesAt, start, limit)
+    }
+
+    pub fn series_manager(env: Env, isin: String) -> Address {
+        read_persistent(&env, &DataKey::SeriesManager(isin))
+            .unwrap_or_else(|| panic_with_error!(&env, BondStorageError::SeriesNotFound))
+    }
+
+    pub fn institution(env: Env, isin: String) -> Address {
+        Self::issuer_info(env, isin).institution
+    }
+
+    pub fn cnpj(env: Env, isin: String) -> String {
+        Self::issuer_info(env, isin).cnpj
+    }
+
+    pub fn lei(env: Env, isin: String) -> String {
+        Self::issuer_info(env, isin).lei
+    }
+
+    pub fn bond_info(env: Env, isin: String) -> Bond {
+        read_persistent(&env, &DataKey::Bond(isin))
+            .unwrap_or_else(|| panic_with_error!(&env, BondStorageError::SeriesNotFound))
+    }
+
+    pub fn bond_status(env: Env, isin: String) -> BondStatus {
+        Self::bond_info(env, isin).bond_status
+    }
+
+    // Zero until the offering is closed
+    pub fn principal(env: Env, isin: String) -> Principal {
+        read_persistent(&env, &DataKey::Principal(isin)).unwrap_or_default()
+    }
+
+    pub fn schedule(env: Env, isin: String) -> AmortizationSchedule {
+        read_persistent(&env, &DataKey::Amortization(isin))
+            .unwrap_or_else(|| panic_with_error!(&env, BondStorageError::ScheduleNotFound))
+    }
+
+    // Date of the first principal payment not yet due, or None once all have passed
+    pub fn next_payment_date(env: Env, isin: String) -> Option<u64> {
+        let now = env.ledger().timestamp();
+        Self::schedule(env, isin).payments.iter().map(|payment| payment.date).find(|&date| date >= now)
+    }
+
+    pub fn trustee(env: Env, isin: String) -> Option<Address> {
+        read_persistent(&env, &DataKey::Trustee(isin))
+    }
+
+    pub fn covenants(env: Env, isin: String) -> Vec<Covenant> {
+        read_persistent(&env, &DataKey::Covenants(isin)).unwrap_or(Vec::new(&env))
+    }
+
+    pub fn breach_count(env: Env, isin: String) -> u32 {
+        read_persistent(&env, &DataKey::BreachCount(isin)).unwrap_or(0)
+    }
+
+    pub fn breach(env: Env, isin: String, breach_id: u32) -> Breach {
+        read_persistent(&env, &DataKey::Breach(isin, breach_id))
+            .unwrap_or_else(|| panic_with_error!(&env, BondStorageError::BreachNotFound))
+    }
+
+    // Returns up to `limit` breaches (capped at MAX_PAGE_SIZE) in the order reported
+    pub fn breaches(env: Env, isin: String, start: u32, limit: u32) -> Vec<Breach> {
+        let count = Self::breach_count(env.clone(), isin.clone());
+        read_page(&env, count, |i| DataKey::Breach(isin.clone(), i), start, limit)
+    }
+
+    // None while no guarantee has been registered for the series
+    pub fn guarantee(env: Env, isin: String) -> Option<Guarantee> {
+        read_persistent(&env, &DataKey::Guarantee(isin))
+    }
+
+    pub fn is_rating_agency(env: Env, agency: Address) -> bool {
+        env.storage().persistent().has(&DataKey::RatingAgency(agency))
+    }
+
+    // Most recent rating published on the series, by any agency
+    pub fn current_rating(env: Env, isin: String) -> Option<Rating> {
+        let count = Self::rating_count(env.clone(), isin.clone());
+        if count == 0 {
+            return None;
+        }
+        read_persistent(&env, &DataKey::Rating(isin, count - 1))
+    }
+
+    pub fn rating_count(env: Env, isin: String) -> u32 {
+        read_persistent(&env, &DataKey::RatingCount(isin)).unwrap_or(0)
+    }
+
+    // Returns up to `limit` ratings (capped at MAX_PAGE_SIZE), oldest first
+    pub fn rating_history(env: Env, isin: String, start: u32, limit: u32) -> Vec<Rating> {
+        let count = Self::rating_count(env.clone(), isin.clone());
+        read_page(&env, count, |i| DataKey::Rating(isin.clone(), i), start, limit)
+    }
+
+    pub fn document_count(env: Env, isin: String) -> u32 {
+        read_persistent(&env, &DataKey::DocumentCount(isin)).unwrap_or(0)
+    }
+
+    // Returns up to `limit` documents (capped at MAX_PAGE_SIZE), indenture and amendments
+    // in the order they were anchored
+    pub fn documents(env: Env, isin: String, start: u32, limit: u32) -> Vec<Document> {
+        let count = Self::document_count(env.clone(), isin.clone());
+        read_page(&env, count, |i| DataKey::Document(isin.clone(), i), start, limit)
     }
 
-    pub fn cnpj(env: Env) -> String {
-        let isin: String = env.storage().instance().get(&"bondISIN").unwrap();
-        let issuer: Issuer = env.storage().instance().get(&(isin, "issuer")).unwrap();
-        issuer.cnpj
+    // Number of recorded versions; the latest one is `version_count - 1`
+    pub fn version_count(env: Env, isin: String) -> u32 {
+        read_persistent(&env, &DataKey::VersionCount(isin)).unwrap_or(0)
     }
 
-    pub fn lei(env: Env) -> String {
-        let isin: String = env.storage().instance().get(&"bondISIN").unwrap();
-        let issuer: Issuer = env.storage().instance().get(&(isin, "issuer")).unwrap();
-        issuer.lei
+    pub fn bond_info_at(env: Env, isin: String, version: u32) -> TermsVersion {
+        read_persistent(&env, &DataKey::Version(isin, version))
+            .unwrap_or_else(|| panic_with_error!(&env, BondStorageError::VersionNotFound))
     }
 
-    pub fn bond_info(env: Env) -> Bond {
-        let isin: String = env.storage().instance().get(&"bondISIN").unwrap();
-        env.storage().instance().get(&(isin, "bond")).unwrap()
+    // Returns up to `limit` versions (capped at MAX_PAGE_SIZE), oldest first
+    pub fn bond_history(env: Env, isin: String, start: u32, limit: u32) -> Vec<TermsVersion> {
+        let count = Self::version_count(env.clone(), isin.clone());
+        read_page(&env, count, |version| DataKey::Version(isin.clone(), version), start, limit)
     }
 
-    pub fn currency(env: Env) -> Address {
-        Self::bond_info(env).currency
+    pub fn currency(env: Env, isin: String) -> Address {
+        Self::bond_info(env, isin).currency
     }
 
-    pub fn denomination(env: Env) -> i128 {
-        Self::bond_info(env).denomination
+    pub fn denomination(env: Env, isin: String) -> i128 {
+        Self::bond_info(env, isin).denomination
     }
 
-    pub fn frequency(env: Env) -> u64 {
-        Self::bond_info(env).frequency
+    pub fn frequency(env: Env, isin: String) -> u64 {
+        Self::bond_info(env, isin).frequency
     }
 
-    pub fn interest_rate(env: Env) -> i128 {
-        Self::bond_info(env).interest_rate
+    pub fn remuneration(env: Env, isin: String) -> Remuneration {
+        Self::bond_info(env, isin).remuneration
     }
 
-    pub fn issue_date(env: Env) -> u64 {
-        Self::bond_info(env).issue_date
+    pub fn issue_date(env: Env, isin: String) -> u64 {
+        Self::bond_info(env, isin).issue_date
     }
 
-    pub fn maturity_date(env: Env) -> u64 {
-        Self::bond_info(env).maturity_date
+    pub fn maturity_date(env: Env, isin: String) -> u64 {
+        Self::bond_info(env, isin).maturity_date
     }
 
-    pub fn issuer_info(env: Env) -> Issuer {
-        let isin: String = env.storage().instance().get(&"bondISIN").unwrap();
-        env.storage().instance().get(&(isin, "issuer")).unwrap()
+    pub fn issuer_info(env: Env, isin: String) -> Issuer {
+        read_persistent(&env, &DataKey::Issuer(isin))
+            .unwrap_or_else(|| panic_with_error!(&env, BondStorageError::SeriesNotFound))
     }
 }
@@ -1,5 +1,114 @@
 #![no_std]
 pub mod bond_storage;
+pub mod validation;
 
 #[cfg(test)]
 pub mod test;
+
+use soroban_sdk::{contracterror, contractevent, Address, BytesN, String};
+use crate::bond_storage::{BondStatus, DocumentType, GuaranteeType, RatingAction};
+
+// topics ["status", isin], data {from, to}
+#[contractevent(topics = ["status"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StatusChangedEvent {
+    #[topic]
+    pub isin: String,
+    pub from: BondStatus,
+    pub to: BondStatus,
+}
+
+// topics ["principal_paid", isin], data {amount, total_paid}
+#[contractevent(topics = ["principal_paid"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrincipalPaidEvent {
+    #[topic]
+    pub isin: String,
+    pub amount: i128,
+    pub total_paid: i128,
+}
+
+// topics ["document", isin], data {index, doc_type, hash}
+#[contractevent(topics = ["document"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DocumentAnchoredEvent {
+    #[topic]
+    pub isin: String,
+    pub index: u32,
+    pub doc_type: DocumentType,
+    pub hash: BytesN<32>,
+}
+
+// topics ["breach", isin], data {breach_id, covenant_id, observed}
+#[contractevent(topics = ["breach"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BreachReportedEvent {
+    #[topic]
+    pub isin: String,
+    pub breach_id: u32,
+    pub covenant_id: u32,
+    pub observed: i128,
+}
+
+// topics ["early_maturity", isin], data breach_id
+#[contractevent(topics = ["early_maturity"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EarlyMaturityEvent {
+    #[topic]
+    pub isin: String,
+    pub breach_id: u32,
+}
+
+// topics ["rating_change", isin, agency], data {action, previous_grade, grade}
+#[contractevent(topics = ["rating_change"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RatingChangedEvent {
+    #[topic]
+    pub isin: String,
+    #[topic]
+    pub agency: Address,
+    pub action: RatingAction,
+    pub previous_grade: String,
+    pub grade: String,
+}
+
+// topics ["guarantee", isin], data {guarantee_type, coverage_ratio}
+#[contractevent(topics = ["guarantee"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuaranteeUpdatedEvent {
+    #[topic]
+    pub isin: String,
+    pub guarantee_type: GuaranteeType,
+    pub coverage_ratio: i128,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    SeriesAlreadyRegistered = 2,
+    SeriesNotFound = 3,
+    InvalidIsin = 4,
+    InvalidCnpj = 5,
+    InvalidLei = 6,
+    InvalidStatusTransition = 7,
+    NotYetMatured = 8,
+    PrincipalOutstanding = 9,
+    InvalidAmount = 10,
+    IndentureAlreadyAnchored = 11,
+    MissingIndenture = 12,
+    DuplicateDocument = 13,
+    VersionNotFound = 14,
+    InvalidRemuneration = 15,
+    InvalidSchedule = 16,
+    ScheduleNotFound = 17,
+    TrusteeNotSet = 18,
+    CovenantNotFound = 19,
+    NotABreach = 20,
+    BreachNotFound = 21,
+    BreachNotPending = 22,
+    NotRatingAgency = 23,
+    InvalidRating = 24,
+    InvalidGuarantee = 25,
+    TooManyCovenants = 26,
+}
@@ -0,0 +1,971 @@
+#![cfg(test)]
+
+extern crate std;
+
+use super::bond_storage::{
+    Amortization, AmortizationKind, AmortizationPlan, Bond, BondStatus, BreachStatus, Collateral, CollateralRef,
+    Covenant, CovenantBound, GuaranteeType, DayCount, DocumentType, Indexer, Issuer, BondStorage, BondStorageClient, Principal,
+    Outlook, Rating, RatingAction, RatingScale, Remuneration, DataKey, MAX_COVENANTS, MAX_GUARANTORS, RATE_SCALE, SERIES_BUMP_AMOUNT,
+    SERIES_LIFETIME_THRESHOLD,
+};
+use super::validation::{is_valid_cnpj, is_valid_isin, is_valid_lei};
+use super::Error;
+use debenture_types::testutils::{measure, Footprint};
+use soroban_sdk::testutils::{storage::Persistent as _, Address as _, EnvTestConfig, Events, Ledger, MockAuth, MockAuthInvoke};
+use soroban_sdk::{map, symbol_short, vec, Address, Bytes, BytesN, Env, IntoVal, Map, String, Symbol, Val, Vec};
+
+const ISIN: &str = "BREMISDEB5H1";
+const OTHER_ISIN: &str = "BREMISDEB6H9";
+
+fn deploy_contract<'a>(env: &Env, manager: &Address) -> (Address, BondStorageClient<'a>) {
+    let contract_id = env.register(BondStorage, (manager,));
+    let client = BondStorageClient::new(env, &contract_id);
+    (contract_id, client)
+}
+
+// CDI + 1.5% a.a., DU/252
+fn sample_remuneration() -> Remuneration {
+    Remuneration {
+        indexer: Indexer::Cdi,
+        index_percentage: RATE_SCALE,
+        spread: 15_000_000,
+        day_count: DayCount::Du252,
+    }
+}
+
+fn sample_bond(env: &Env) -> Bond {
+    Bond {
+        currency: Address::generate(env),
+        denomination: 1000,
+        remuneration: sample_remuneration(),
+        frequency: 2,
+        issue_number: 1,
+        issue_date: 1678886400,
+        maturity_date: 1710499200,
+        bond_status: BondStatus::Offered,
+    }
+}
+
+fn sample_issuer(env: &Env) -> Issuer {
+    Issuer {
+        institution: Address::generate(env),
+        cnpj: String::from_str(env, "11222333000181"),
+        lei: String::from_str(env, "5493001KJTIIGC8Y1R12"),
+    }
+}
+
+// Deploys and registers ISIN with the manager's authorization
+fn setup<'a>(env: &Env) -> (Address, BondStorageClient<'a>, Address) {
+    let manager = Address::generate(env);
+    let (contract_id, client) = deploy_contract(env, &manager);
+
+    env.mock_all_auths();
+    client.register_series(&String::from_str(env, ISIN), &sample_bond(env), &sample_issuer(env));
+    env.set_auths(&[]);
+    (contract_id, client, manager)
+}
+
+#[test]
+fn test_constructor_runs_once() {
+    let env = Env::default();
+    let manager = Address::generate(&env);
+    let (contract_id, client) = deploy_contract(&env, &manager);
+    assert_eq!(client.series_count(), 0);
+
+    let args = vec![&env, Address::generate(&env).into_val(&env)];
+    let result = env.try_invoke_contract::<(), Error>(&contract_id, &Symbol::new(&env, "__constructor"), args);
+    assert!(result.is_err());
+    let stored: Address = env.as_contract(&contract_id, || env.storage().instance().get(&DataKey::Manager).unwrap());
+    assert_eq!(stored, manager);
+}
+
+#[test]
+fn test_series_entries_ttl_bumped() {
+    let env = Env::default();
+    let (contract_id, client, _manager) = setup(&env);
+    let isin = String::from_str(&env, ISIN);
+    let key = DataKey::Bond(isin.clone());
+    let ttl = || env.as_contract(&contract_id, || env.storage().persistent().get_ttl(&key));
+    assert_eq!(ttl(), SERIES_BUMP_AMOUNT);
+
+    // Reads keep a series alive for months after its last write
+    env.ledger().with_mut(|li| li.sequence_number += SERIES_BUMP_AMOUNT - SERIES_LIFETIME_THRESHOLD + 1);
+    assert_eq!(ttl(), SERIES_LIFETIME_THRESHOLD - 1);
+    client.bond_info(&isin);
+    assert_eq!(ttl(), SERIES_BUMP_AMOUNT);
+}
+
+#[test]
+fn test_register_series() {
+    let env = Env::default();
+    let (_contract_id, client, manager) = setup(&env);
+    let isin = String::from_str(&env, ISIN);
+
+    assert_eq!(client.series_count(), 1);
+    assert_eq!(client.series_manager(&isin), manager);
+    assert_eq!(client.denomination(&isin), 1000);
+    assert_eq!(client.cnpj(&isin), String::from_str(&env, "11222333000181"));
+
+    env.mock_all_auths();
+    let result = client.try_register_series(&isin, &sample_bond(&env), &sample_issuer(&env));
+    assert_eq!(result, Err(Ok(Error::SeriesAlreadyRegistered.into())));
+
+    let result = client.try_bond_info(&String::from_str(&env, OTHER_ISIN));
+    assert_eq!(result, Err(Ok(Error::SeriesNotFound.into())));
+}
+
+#[test]
+fn test_set_and_get_issuer() {
+    let env = Env::default();
+    let (contract_id, client, manager) = setup(&env);
+    let isin = String::from_str(&env, ISIN);
+
+    let issuer_institution = Address::generate(&env);
+    let issuer_data = Issuer {
+        institution: issuer_institution.clone(),
+        cnpj: String::from_str(&env, "12345678000195"),
+        lei: String::from_str(&env, "529900T8BM49AURSDO55"),
+    };
+
+    env.mock_auths(&[
+        MockAuth {
+            address: &manager,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "set_issuer",
+                args: (&isin, &issuer_data).into_val(&env),
+                sub_invokes: &[]
+            }
+        }
+    ]);
+    client.set_issuer(&isin, &issuer_data);
+
+    let retrieved_issuer = client.issuer_info(&isin);
+    assert_eq!(retrieved_issuer.institution, issuer_institution);
+    assert_eq!(retrieved_issuer.cnpj, issuer_data.cnpj);
+    assert_eq!(retrieved_issuer.lei, issuer_data.lei);
+}
+
+#[test]
+fn test_set_and_get_bond() {
+    let env = Env::default();
+    let (contract_id, client, manager) = setup(&env);
+    let isin = String::from_str(&env, ISIN);
+
+    let currency_address = Address::generate(&env);
+    let bond_data = Bond {
+        currency: currency_address.clone(),
+        denomination: 2000,
+        remuneration: sample_remuneration(),
+        frequency: 2,
+        issue_number: 1,
+        issue_date: 1678886400,
+        maturity_date: 1710499200,
+        bond_status: BondStatus::Offered,
+    };
+
+    env.mock_auths(&[
+        MockAuth {
+            address: &manager,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "set_bond",
+                args: (&isin, &bond_data).into_val(&env),
+                sub_invokes: &[]
+            }
+        }
+    ]);
+    client.set_bond(&isin, &bond_data);
+
+    let retrieved_bond = client.bond_info(&isin);
+    assert_eq!(retrieved_bond.currency, currency_address);
+    assert_eq!(retrieved_bond.denomination, 2000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn test_unauthorized_set_issuer() {
+    let env = Env::default();
+    let (_contract_id, client, _manager) = setup(&env);
+
+    client.set_issuer(&String::from_str(&env, ISIN), &sample_issuer(&env));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn test_unauthorized_set_bond() {
+    let env = Env::default();
+    let (_contract_id, client, _manager) = setup(&env);
+
+    let bond_data = Bond {
+        currency: Address::generate(&env),
+        denomination: 1, remuneration: sample_remuneration(), frequency: 1, issue_number: 1,
+        issue_date: 1, maturity_date: 1, bond_status: BondStatus::Issued,
+    };
+
+    client.set_bond(&String::from_str(&env, ISIN), &bond_data);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn test_unauthorized_register_series() {
+    let env = Env::default();
+    let (_contract_id, client, _manager) = setup(&env);
+
+    client.register_series(&String::from_str(&env, OTHER_ISIN), &sample_bond(&env), &sample_issuer(&env));
+}
+
+#[test]
+fn test_list_series_pagination() {
+    let env = Env::default();
+    let (_contract_id, client, _manager) = setup(&env);
+    let other = String::from_str(&env, OTHER_ISIN);
+    let third = String::from_str(&env, "BREMISDEB7H7");
+
+    env.mock_all_auths();
+    client.register_series(&other, &sample_bond(&env), &sample_issuer(&env));
+    client.register_series(&third, &sample_bond(&env), &sample_issuer(&env));
+
+    assert_eq!(client.series_count(), 3);
+    assert_eq!(client.list_series(&0, &2), vec![&env, String::from_str(&env, ISIN), other.clone()]);
+    assert_eq!(client.list_series(&2, &2), vec![&env, third]);
+    assert_eq!(client.list_series(&3, &2), vec![&env]);
+}
+
+#[test]
+fn test_series_manager_permissions() {
+    let env = Env::default();
+    let (contract_id, client, manager) = setup(&env);
+    let isin = String::from_str(&env, ISIN);
+    let series_manager = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.set_series_manager(&isin, &series_manager);
+    assert_eq!(client.series_manager(&isin), series_manager);
+
+    // The registry manager no longer controls this series
+    let bond_data = sample_bond(&env);
+    env.mock_auths(&[
+        MockAuth {
+            address: &manager,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "set_bond",
+                args: (&isin, &bond_data).into_val(&env),
+                sub_invokes: &[]
+            }
+        }
+    ]);
+    assert!(client.try_set_bond(&isin, &bond_data).is_err());
+
+    env.mock_auths(&[
+        MockAuth {
+            address: &series_manager,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "set_bond",
+                args: (&isin, &bond_data).into_val(&env),
+                sub_invokes: &[]
+            }
+        }
+    ]);
+    client.set_bond(&isin, &bond_data);
+    assert_eq!(client.currency(&isin), bond_data.currency);
+}
+
+#[test]
+fn test_identifier_checksums() {
+    let env = Env::default();
+    let valid = |f: fn(&String) -> bool, value: &str| f(&String::from_str(&env, value));
+
+    assert!(valid(is_valid_isin, "BREMISDEB5H1"));
+    assert!(valid(is_valid_isin, "US0378331005"));
+    assert!(!valid(is_valid_isin, "BREMISDEB5H2"));
+    assert!(!valid(is_valid_isin, "BREMISDEB5H"));
+    assert!(!valid(is_valid_isin, "bremisdeb5h1"));
+    assert!(!valid(is_valid_isin, "12EMISDEB5H1"));
+
+    assert!(valid(is_valid_cnpj, "11222333000181"));
+    assert!(valid(is_valid_cnpj, "12345678000195"));
+    assert!(!valid(is_valid_cnpj, "11222333000182"));
+    assert!(!valid(is_valid_cnpj, "11.222.333/0001-81"));
+    assert!(!valid(is_valid_cnpj, "00000000000000"));
+
+    // Alphanumeric CNPJ: letters allowed in the base, check digits stay numeric
+    assert!(valid(is_valid_cnpj, "12ABC34501DE35"));
+    assert!(!valid(is_valid_cnpj, "12ABC34501DE36"));
+    assert!(!valid(is_valid_cnpj, "12abc34501de35"));
+    assert!(!valid(is_valid_cnpj, "12ABC34501DE3F"));
+
+    assert!(valid(is_valid_lei, "5493001KJTIIGC8Y1R12"));
+    assert!(valid(is_valid_lei, "529900T8BM49AURSDO55"));
+    assert!(!valid(is_valid_lei, "5493001KJTIIGC8Y1R13"));
+    assert!(!valid(is_valid_lei, "5493001kjtiigc8y1r12"));
+    assert!(!valid(is_valid_lei, "5493001KJTIIGC8Y1R1"));
+}
+
+#[test]
+fn test_register_series_rejects_malformed_identifiers() {
+    let env = Env::default();
+    let (_contract_id, client, _manager) = setup(&env);
+    env.mock_all_auths();
+    let isin = String::from_str(&env, OTHER_ISIN);
+
+    let result = client.try_register_series(&String::from_str(&env, "BREMISDEB5H2"), &sample_bond(&env), &sample_issuer(&env));
+    assert_eq!(result, Err(Ok(Error::InvalidIsin.into())));
+
+    let mut issuer = sample_issuer(&env);
+    issuer.cnpj = String::from_str(&env, "11222333000182");
+    let result = client.try_register_series(&isin, &sample_bond(&env), &issuer);
+    assert_eq!(result, Err(Ok(Error::InvalidCnpj.into())));
+
+    let mut issuer = sample_issuer(&env);
+    issuer.lei = String::from_str(&env, "5493001KJTIIGC8Y1R13");
+    let result = client.try_register_series(&isin, &sample_bond(&env), &issuer);
+    assert_eq!(result, Err(Ok(Error::InvalidLei.into())));
+
+    assert_eq!(client.series_count(), 1);
+}
+
+#[test]
+fn test_set_issuer_accepts_alphanumeric_cnpj() {
+    let env = Env::default();
+    let (_contract_id, client, _manager) = setup(&env);
+    env.mock_all_auths();
+    let isin = String::from_str(&env, ISIN);
+
+    let cnpj = String::from_str(&env, "12ABC34501DE35");
+    client.set_issuer(&isin, &Issuer { cnpj: cnpj.clone(), ..sample_issuer(&env) });
+    assert_eq!(client.cnpj(&isin), cnpj);
+}
+
+#[test]
+fn test_set_issuer_rejects_malformed_identifiers() {
+    let env = Env::default();
+    let (_contract_id, client, _manager) = setup(&env);
+    env.mock_all_auths();
+    let isin = String::from_str(&env, ISIN);
+
+    let mut issuer = sample_issuer(&env);
+    issuer.cnpj = String::from_str(&env, "1122233300018");
+    let result = client.try_set_issuer(&isin, &issuer);
+    assert_eq!(result, Err(Ok(Error::InvalidCnpj.into())));
+
+    let mut issuer = sample_issuer(&env);
+    issuer.lei = String::from_str(&env, "LEI-INVALIDO");
+    let result = client.try_set_issuer(&isin, &issuer);
+    assert_eq!(result, Err(Ok(Error::InvalidLei.into())));
+
+    assert_eq!(client.cnpj(&isin), String::from_str(&env, "11222333000181"));
+}
+
+#[test]
+fn test_status_lifecycle() {
+    let env = Env::default();
+    let (contract_id, client, _manager) = setup(&env);
+    env.mock_all_auths();
+    let isin = String::from_str(&env, ISIN);
+
+    // Registration ignores the status passed in, and so does set_bond
+    assert_eq!(client.bond_status(&isin), BondStatus::Registered);
+    client.set_bond(&isin, &Bond { bond_status: BondStatus::Redeemed, ..sample_bond(&env) });
+    assert_eq!(client.bond_status(&isin), BondStatus::Registered);
+
+    client.open_offering(&isin);
+    let data: Map<Symbol, Val> = map![
+        &env,
+        (symbol_short!("from"), BondStatus::Registered.into_val(&env)),
+        (symbol_short!("to"), BondStatus::Offered.into_val(&env)),
+    ];
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_id,
+                (symbol_short!("status"), isin.clone()).into_val(&env),
+                data.into_val(&env),
+            ),
+        ]
+    );
+
+    client.close_offering(&isin, &1_000_000);
+    assert_eq!(client.bond_status(&isin), BondStatus::Issued);
+    assert_eq!(client.principal(&isin), Principal { placed: 1_000_000, paid: 0 });
+
+    client.record_principal_payment(&isin, &400_000);
+    let result = client.try_mark_matured(&isin);
+    assert_eq!(result, Err(Ok(Error::NotYetMatured.into())));
+
+    env.ledger().with_mut(|li| li.timestamp = 1710499200);
+    client.mark_matured(&isin);
+    let result = client.try_mark_redeemed(&isin);
+    assert_eq!(result, Err(Ok(Error::PrincipalOutstanding.into())));
+
+    let result = client.try_record_principal_payment(&isin, &600_001);
+    assert_eq!(result, Err(Ok(Error::InvalidAmount.into())));
+    client.record_principal_payment(&isin, &600_000);
+    client.mark_redeemed(&isin);
+    assert_eq!(client.bond_status(&isin), BondStatus::Redeemed);
+}
+
+#[test]
+fn test_illegal_status_transitions() {
+    let env = Env::default();
+    let (_contract_id, client, _manager) = setup(&env);
+    env.mock_all_auths();
+    let isin = String::from_str(&env, ISIN);
+
+    let result = client.try_close_offering(&isin, &0);
+    assert_eq!(result, Err(Ok(Error::InvalidStatusTransition.into())));
+    let result = client.try_record_principal_payment(&isin, &1);
+    assert_eq!(result, Err(Ok(Error::InvalidStatusTransition.into())));
+
+    client.open_offering(&isin);
+    let result = client.try_open_offering(&isin);
+    assert_eq!(result, Err(Ok(Error::InvalidStatusTransition.into())));
+
+    // Past maturity, but the offering was never closed
+    env.ledger().with_mut(|li| li.timestamp = 1710499200);
+    let result = client.try_mark_matured(&isin);
+    assert_eq!(result, Err(Ok(Error::InvalidStatusTransition.into())));
+    let result = client.try_mark_redeemed(&isin);
+    assert_eq!(result, Err(Ok(Error::InvalidStatusTransition.into())));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn test_unauthorized_open_offering() {
+    let env = Env::default();
+    let (_contract_id, client, _manager) = setup(&env);
+
+    client.open_offering(&String::from_str(&env, ISIN));
+}
+
+fn sha256(env: &Env, contents: &str) -> BytesN<32> {
+    env.crypto().sha256(&Bytes::from_slice(env, contents.as_bytes())).into()
+}
+
+#[test]
+fn test_anchor_documents() {
+    let env = Env::default();
+    let (_contract_id, client, _manager) = setup(&env);
+    env.mock_all_auths();
+    let isin = String::from_str(&env, ISIN);
+    let indenture = sha256(&env, "escritura de emissao");
+    let amendment = sha256(&env, "primeiro aditamento");
+
+    // Amendments only make sense once the indenture is on-chain
+    let result = client.try_anchor_document(&isin, &DocumentType::Amendment, &amendment, &String::from_str(&env, "ipfs://aditamento-1"), &1678900000);
+    assert_eq!(result, Err(Ok(Error::MissingIndenture.into())));
+
+    env.ledger().with_mut(|li| li.timestamp = 1678890000);
+    assert_eq!(client.anchor_document(&isin, &DocumentType::Indenture, &indenture, &String::from_str(&env, "ipfs://escritura"), &1678886400), 0);
+    assert_eq!(client.anchor_document(&isin, &DocumentType::Amendment, &amendment, &String::from_str(&env, "ipfs://aditamento-1"), &1678900000), 1);
+
+    assert_eq!(client.document_count(&isin), 2);
+    let documents = client.documents(&isin, &0, &10);
+    assert_eq!(documents.len(), 2);
+    assert_eq!(documents.get(0).unwrap().hash, sha256(&env, "escritura de emissao"));
+    assert_eq!(documents.get(0).unwrap().anchored_at, 1678890000);
+    assert_eq!(documents.get(1).unwrap().doc_type, DocumentType::Amendment);
+    assert_eq!(documents.get(1).unwrap().effective_date, 1678900000);
+
+    let result = client.try_anchor_document(&isin, &DocumentType::Indenture, &sha256(&env, "outra escritura"), &String::from_str(&env, "ipfs://x"), &0);
+    assert_eq!(result, Err(Ok(Error::IndentureAlreadyAnchored.into())));
+    let result = client.try_anchor_document(&isin, &DocumentType::Other, &amendment, &String::from_str(&env, "ipfs://x"), &0);
+    assert_eq!(result, Err(Ok(Error::DuplicateDocument.into())));
+
+    assert_eq!(client.documents(&String::from_str(&env, OTHER_ISIN), &0, &10).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn test_unauthorized_anchor_document() {
+    let env = Env::default();
+    let (_contract_id, client, _manager) = setup(&env);
+
+    client.anchor_document(&String::from_str(&env, ISIN), &DocumentType::Indenture, &sha256(&env, "escritura"), &String::from_str(&env, "ipfs://escritura"), &0);
+}
+
+#[test]
+fn test_bond_history() {
+    let env = Env::default();
+    let (_contract_id, client, manager) = setup(&env);
+    env.mock_all_auths();
+    let isin = String::from_str(&env, ISIN);
+    let series_manager = Address::generate(&env);
+    client.set_series_manager(&isin, &series_manager);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 100;
+        li.timestamp = 1678890000;
+    });
+    client.set_bond(&isin, &Bond { denomination: 2000, ..sample_bond(&env) });
+    env.ledger().with_mut(|li| li.sequence_number = 200);
+    client.set_issuer(&isin, &Issuer { cnpj: String::from_str(&env, "12345678000195"), ..sample_issuer(&env) });
+    client.open_offering(&isin);
+
+    assert_eq!(client.version_count(&isin), 4);
+    let registered = client.bond_info_at(&isin, &0);
+    assert_eq!(registered.bond.denomination, 1000);
+    assert_eq!(registered.author, manager);
+
+    let repriced = client.bond_info_at(&isin, &1);
+    assert_eq!(repriced.bond.denomination, 2000);
+    assert_eq!(repriced.issuer.cnpj, String::from_str(&env, "11222333000181"));
+    assert_eq!((repriced.ledger, repriced.timestamp), (100, 1678890000));
+    assert_eq!(repriced.author, series_manager);
+
+    let history = client.bond_history(&isin, &2, &10);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap().issuer.cnpj, String::from_str(&env, "12345678000195"));
+    assert_eq!(history.get(0).unwrap().ledger, 200);
+    assert_eq!(history.get(1).unwrap().bond.bond_status, BondStatus::Offered);
+
+    let result = client.try_bond_info_at(&isin, &4);
+    assert_eq!(result, Err(Ok(Error::VersionNotFound.into())));
+}
+
+#[test]
+fn test_remuneration() {
+    let env = Env::default();
+    let (_contract_id, client, _manager) = setup(&env);
+    env.mock_all_auths();
+    let isin = String::from_str(&env, ISIN);
+    assert_eq!(client.remuneration(&isin), sample_remuneration());
+
+    // IPCA + 6% a.a., then 110% do CDI, then prefixado 12% a.a.
+    let ipca = Remuneration { indexer: Indexer::Ipca, index_percentage: RATE_SCALE, spread: 60_000_000, day_count: DayCount::Du252 };
+    let cdi_percentage = Remuneration { indexer: Indexer::Cdi, index_percentage: 1_100_000_000, spread: 0, day_count: DayCount::Du252 };
+    let fixed = Remuneration { indexer: Indexer::Prefixado, index_percentage: 0, spread: 120_000_000, day_count: DayCount::Act365 };
+    for remuneration in [ipca, cdi_percentage, fixed] {
+        client.set_bond(&isin, &Bond { remuneration: remuneration.clone(), ..sample_bond(&env) });
+        assert_eq!(client.remuneration(&isin), remuneration);
+    }
+
+    let invalid = [
+        Remuneration { indexer: Indexer::Prefixado, index_percentage: RATE_SCALE, spread: 120_000_000, day_count: DayCount::Thirty360 },
+        Remuneration { indexer: Indexer::Prefixado, index_percentage: 0, spread: 0, day_count: DayCount::Thirty360 },
+        Remuneration { indexer: Indexer::Cdi, index_percentage: 0, spread: 15_000_000, day_count: DayCount::Du252 },
+        Remuneration { indexer: Indexer::Ipca, index_percentage: RATE_SCALE, spread: -1, day_count: DayCount::Du252 },
+    ];
+    for remuneration in invalid {
+        let result = client.try_set_bond(&isin, &Bond { remuneration, ..sample_bond(&env) });
+        assert_eq!(result, Err(Ok(Error::InvalidRemuneration.into())));
+    }
+
+    let bond = Bond { remuneration: Remuneration { spread: -1, ..sample_remuneration() }, ..sample_bond(&env) };
+    let result = client.try_register_series(&String::from_str(&env, OTHER_ISIN), &bond, &sample_issuer(&env));
+    assert_eq!(result, Err(Ok(Error::InvalidRemuneration.into())));
+}
+
+#[test]
+fn test_amortization_schedules() {
+    let env = Env::default();
+    let (_contract_id, client, _manager) = setup(&env);
+    env.mock_all_auths();
+    let isin = String::from_str(&env, ISIN);
+    let maturity = 1710499200;
+
+    let result = client.try_schedule(&isin);
+    assert_eq!(result, Err(Ok(Error::ScheduleNotFound.into())));
+
+    client.set_amortization(&isin, &AmortizationPlan::Bullet);
+    let schedule = client.schedule(&isin);
+    assert_eq!(schedule.kind, AmortizationKind::Bullet);
+    assert_eq!(schedule.payments, vec![&env, Amortization { date: maturity, percentage: RATE_SCALE }]);
+
+    // SAC in three installments: the rounding remainder goes to the last one
+    let dates = vec![&env, maturity - 2_000, maturity - 1_000, maturity];
+    client.set_amortization(&isin, &AmortizationPlan::Linear(dates));
+    let payments = client.schedule(&isin).payments;
+    assert_eq!(payments.get(0).unwrap().percentage, 333_333_333);
+    assert_eq!(payments.get(2).unwrap().percentage, 333_333_334);
+
+    env.ledger().with_mut(|li| li.timestamp = maturity - 1_500);
+    assert_eq!(client.next_payment_date(&isin), Some(maturity - 1_000));
+    env.ledger().with_mut(|li| li.timestamp = maturity + 1);
+    assert_eq!(client.next_payment_date(&isin), None);
+
+    let custom = vec![
+        &env,
+        Amortization { date: maturity - 1_000, percentage: 200_000_000 },
+        Amortization { date: maturity, percentage: 800_000_000 },
+    ];
+    client.set_amortization(&isin, &AmortizationPlan::Custom(custom.clone()));
+    assert_eq!(client.schedule(&isin).kind, AmortizationKind::Custom);
+    assert_eq!(client.schedule(&isin).payments, custom);
+
+    // A new denomination keeps the table; a new maturity drops it until it is set again
+    client.set_bond(&isin, &Bond { denomination: 5000, ..sample_bond(&env) });
+    assert_eq!(client.schedule(&isin).payments, custom);
+    client.set_bond(&isin, &Bond { maturity_date: maturity + 1_000, ..sample_bond(&env) });
+    let result = client.try_schedule(&isin);
+    assert_eq!(result, Err(Ok(Error::ScheduleNotFound.into())));
+    client.set_amortization(&isin, &AmortizationPlan::Bullet);
+    assert_eq!(client.schedule(&isin).payments.get(0).unwrap().date, maturity + 1_000);
+}
+
+#[test]
+fn test_invalid_amortization_schedules() {
+    let env = Env::default();
+    let (_contract_id, client, _manager) = setup(&env);
+    env.mock_all_auths();
+    let isin = String::from_str(&env, ISIN);
+    let maturity = 1710499200;
+
+    let invalid = [
+        AmortizationPlan::Linear(vec![&env]),
+        // Does not end on the maturity date
+        AmortizationPlan::Linear(vec![&env, maturity - 1_000]),
+        // Adds up to 90%
+        AmortizationPlan::Custom(vec![
+            &env,
+            Amortization { date: maturity - 1_000, percentage: 100_000_000 },
+            Amortization { date: maturity, percentage: 800_000_000 },
+        ]),
+        // Dates out of order
+        AmortizationPlan::Custom(vec![
+            &env,
+            Amortization { date: maturity, percentage: 500_000_000 },
+            Amortization { date: maturity, percentage: 500_000_000 },
+        ]),
+        AmortizationPlan::Custom(vec![
+            &env,
+            Amortization { date: maturity - 1_000, percentage: -100_000_000 },
+            Amortization { date: maturity, percentage: 1_100_000_000 },
+        ]),
+    ];
+    for plan in invalid {
+        let result = client.try_set_amortization(&isin, &plan);
+        assert_eq!(result, Err(Ok(Error::InvalidSchedule.into())));
+    }
+}
+
+// Issued series with a trustee and two covenants: leverage capped at 3.5 (automatic) and
+// interest coverage of at least 1.5 (non-automatic)
+fn setup_covenants<'a>(env: &Env) -> (BondStorageClient<'a>, String, Address) {
+    let (_contract_id, client, _manager) = setup(env);
+    env.mock_all_auths();
+    let isin = String::from_str(env, ISIN);
+    let trustee = Address::generate(env);
+    client.set_trustee(&isin, &trustee);
+    client.open_offering(&isin);
+    client.close_offering(&isin, &1_000_000);
+
+    let leverage = Covenant {
+        description_hash: sha256(env, "divida liquida / EBITDA <= 3,5"),
+        metric: Symbol::new(env, "net_debt_ebitda"),
+        bound: CovenantBound::Max,
+        threshold: 3_500_000_000,
+        automatic: true,
+    };
+    let coverage = Covenant {
+        description_hash: sha256(env, "EBITDA / despesa financeira >= 1,5"),
+        metric: Symbol::new(env, "interest_coverage"),
+        bound: CovenantBound::Min,
+        threshold: 1_500_000_000,
+        automatic: false,
+    };
+    assert_eq!(client.add_covenant(&isin, &leverage), 0);
+    assert_eq!(client.add_covenant(&isin, &coverage), 1);
+    (client, isin, trustee)
+}
+
+#[test]
+fn test_automatic_early_maturity() {
+    let env = Env::default();
+    let (client, isin, trustee) = setup_covenants(&env);
+
+    let result = client.try_report_breach(&isin, &0, &3_400_000_000);
+    assert_eq!(result, Err(Ok(Error::NotABreach.into())));
+    let result = client.try_report_breach(&isin, &2, &0);
+    assert_eq!(result, Err(Ok(Error::CovenantNotFound.into())));
+
+    // Well before the maturity date
+    assert_eq!(client.report_breach(&isin, &0, &4_000_000_000), 0);
+    assert_eq!(client.bond_status(&isin), BondStatus::Matured);
+    assert_eq!(client.breach(&isin, &0).status, BreachStatus::Accelerated);
+
+    let latest = client.bond_info_at(&isin, &(client.version_count(&isin) - 1));
+    assert_eq!(latest.author, trustee);
+
+    // Once matured there is nothing left to accelerate
+    let result = client.try_report_breach(&isin, &1, &1_000_000_000);
+    assert_eq!(result, Err(Ok(Error::InvalidStatusTransition.into())));
+}
+
+#[test]
+fn test_non_automatic_early_maturity() {
+    let env = Env::default();
+    let (client, isin, _trustee) = setup_covenants(&env);
+
+    let waived = client.report_breach(&isin, &1, &1_200_000_000);
+    assert_eq!(client.bond_status(&isin), BondStatus::Issued);
+    client.waive_breach(&isin, &waived);
+    let result = client.try_declare_early_maturity(&isin, &waived);
+    assert_eq!(result, Err(Ok(Error::BreachNotPending.into())));
+
+    let breach_id = client.report_breach(&isin, &1, &1_000_000_000);
+    client.declare_early_maturity(&isin, &breach_id);
+    assert_eq!(client.bond_status(&isin), BondStatus::Matured);
+
+    assert_eq!(client.breach_count(&isin), 2);
+    let breaches = client.breaches(&isin, &0, &10);
+    assert_eq!(breaches.get(0).unwrap().status, BreachStatus::Waived);
+    assert_eq!(breaches.get(1).unwrap().status, BreachStatus::Accelerated);
+    assert_eq!(breaches.get(1).unwrap().observed, 1_000_000_000);
+
+    let result = client.try_waive_breach(&isin, &7);
+    assert_eq!(result, Err(Ok(Error::BreachNotFound.into())));
+}
+
+#[test]
+fn test_only_trustee_reports_breach() {
+    let env = Env::default();
+    let (client, isin, trustee) = setup_covenants(&env);
+    let outsider = Address::generate(&env);
+
+    for signer in [&outsider, &trustee] {
+        env.mock_auths(&[
+            MockAuth {
+                address: signer,
+                invoke: &MockAuthInvoke {
+                    contract: &client.address,
+                    fn_name: "report_breach",
+                    args: (&isin, 0u32, 4_000_000_000i128).into_val(&env),
+                    sub_invokes: &[]
+                }
+            }
+        ]);
+        assert_eq!(client.try_report_breach(&isin, &0, &4_000_000_000).is_ok(), signer == &trustee);
+    }
+}
+
+#[test]
+fn test_report_breach_requires_trustee() {
+    let env = Env::default();
+    let (_contract_id, client, _manager) = setup(&env);
+    env.mock_all_auths();
+
+    let result = client.try_report_breach(&String::from_str(&env, ISIN), &0, &0);
+    assert_eq!(result, Err(Ok(Error::TrusteeNotSet.into())));
+}
+
+#[test]
+fn test_covenants_capped() {
+    let env = Env::default();
+    let (client, isin, _trustee) = setup_covenants(&env);
+    let covenant = client.covenants(&isin).get(0).unwrap();
+
+    for id in 2..MAX_COVENANTS {
+        assert_eq!(client.add_covenant(&isin, &covenant), id);
+    }
+    let result = client.try_add_covenant(&isin, &covenant);
+    assert_eq!(result, Err(Ok(Error::TooManyCovenants.into())));
+}
+
+fn rating(env: &Env, agency: &Address, grade: &str, notch: u32, date: u64) -> Rating {
+    Rating {
+        agency: agency.clone(),
+        scale: RatingScale::National,
+        grade: String::from_str(env, grade),
+        notch,
+        outlook: Outlook::Stable,
+        date,
+        report_hash: sha256(env, grade),
+    }
+}
+
+#[test]
+fn test_rating_history() {
+    let env = Env::default();
+    let (contract_id, client, _manager) = setup(&env);
+    env.mock_all_auths();
+    let isin = String::from_str(&env, ISIN);
+    let agency = Address::generate(&env);
+    let other_agency = Address::generate(&env);
+    client.set_rating_agency(&agency, &true);
+    client.set_rating_agency(&other_agency, &true);
+
+    assert_eq!(client.current_rating(&isin), None);
+    client.publish_rating(&isin, &rating(&env, &agency, "brAA", 3, 1678886400));
+    assert_eq!(env.events().all().len(), 0);
+
+    // Another agency's grade is not compared with this one
+    client.publish_rating(&isin, &rating(&env, &other_agency, "brA+", 5, 1678890000));
+    assert_eq!(env.events().all().len(), 0);
+
+    client.publish_rating(&isin, &rating(&env, &agency, "brAA-", 4, 1678900000));
+    let data: Map<Symbol, Val> = map![
+        &env,
+        (symbol_short!("action"), RatingAction::Downgrade.into_val(&env)),
+        (symbol_short!("grade"), String::from_str(&env, "brAA-").into_val(&env)),
+        (Symbol::new(&env, "previous_grade"), String::from_str(&env, "brAA").into_val(&env)),
+    ];
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_id.clone(),
+                (Symbol::new(&env, "rating_change"), isin.clone(), agency.clone()).into_val(&env),
+                data.into_val(&env),
+            ),
+        ]
+    );
+
+    client.publish_rating(&isin, &rating(&env, &other_agency, "brAA-", 4, 1678910000));
+    let data: Map<Symbol, Val> = map![
+        &env,
+        (symbol_short!("action"), RatingAction::Upgrade.into_val(&env)),
+        (symbol_short!("grade"), String::from_str(&env, "brAA-").into_val(&env)),
+        (Symbol::new(&env, "previous_grade"), String::from_str(&env, "brA+").into_val(&env)),
+    ];
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_id.clone(),
+                (Symbol::new(&env, "rating_change"), isin.clone(), other_agency.clone()).into_val(&env),
+                data.into_val(&env),
+            ),
+        ]
+    );
+
+    assert_eq!(client.rating_count(&isin), 4);
+    assert_eq!(client.rating_history(&isin, &0, &10).len(), 4);
+    assert_eq!(client.rating_history(&isin, &3, &10), vec![&env, rating(&env, &other_agency, "brAA-", 4, 1678910000)]);
+    assert_eq!(client.current_rating(&isin), Some(rating(&env, &other_agency, "brAA-", 4, 1678910000)));
+}
+
+// Publishes `history` ratings, then meters one more
+fn publish_rating_cost(history: u32) -> Footprint {
+    let env = Env::new_with_config(EnvTestConfig { capture_snapshot_at_drop: false });
+    let (_contract_id, client, _manager) = setup(&env);
+    env.mock_all_auths();
+    let isin = String::from_str(&env, ISIN);
+    let agency = Address::generate(&env);
+    client.set_rating_agency(&agency, &true);
+
+    for date in 0..history {
+        client.publish_rating(&isin, &rating(&env, &agency, "brAA", 3, date as u64));
+    }
+
+    let cost = measure(&env, || client.publish_rating(&isin, &rating(&env, &agency, "brAA", 3, history as u64)));
+    assert_eq!(client.rating_count(&isin), history + 1);
+    cost
+}
+
+// Each rating is its own entry, so a long history never stops new ratings from fitting: a
+// rating writes itself, the rating count, the agency's latest index and the agency's auth nonce
+#[test]
+fn test_publish_rating_cost_flat_as_history_grows() {
+    let cost = publish_rating_cost(300);
+    assert_eq!(publish_rating_cost(1), cost);
+    assert_eq!(cost.write_entries, 4);
+}
+
+#[test]
+fn test_publish_rating_requires_authorized_agency() {
+    let env = Env::default();
+    let (_contract_id, client, _manager) = setup(&env);
+    env.mock_all_auths();
+    let isin = String::from_str(&env, ISIN);
+    let agency = Address::generate(&env);
+
+    let result = client.try_publish_rating(&isin, &rating(&env, &agency, "brAA", 3, 0));
+    assert_eq!(result, Err(Ok(Error::NotRatingAgency.into())));
+
+    client.set_rating_agency(&agency, &true);
+    let result = client.try_publish_rating(&isin, &rating(&env, &agency, "brAA", 0, 0));
+    assert_eq!(result, Err(Ok(Error::InvalidRating.into())));
+    let result = client.try_publish_rating(&String::from_str(&env, OTHER_ISIN), &rating(&env, &agency, "brAA", 3, 0));
+    assert_eq!(result, Err(Ok(Error::SeriesNotFound.into())));
+
+    client.set_rating_agency(&agency, &false);
+    assert!(!client.is_rating_agency(&agency));
+    let result = client.try_publish_rating(&isin, &rating(&env, &agency, "brAA", 3, 0));
+    assert_eq!(result, Err(Ok(Error::NotRatingAgency.into())));
+}
+
+#[test]
+fn test_guarantee_registry() {
+    let env = Env::default();
+    let (_contract_id, client, _manager) = setup(&env);
+    env.mock_all_auths();
+    let isin = String::from_str(&env, ISIN);
+
+    let result = client.try_set_guarantee(&isin, &GuaranteeType::Quirografaria, &vec![&env], &0, &vec![&env]);
+    assert_eq!(result, Err(Ok(Error::TrusteeNotSet.into())));
+    client.set_trustee(&isin, &Address::generate(&env));
+    assert_eq!(client.guarantee(&isin), None);
+
+    // Alienação fiduciária of tokenized receivables plus a property, 130% coverage
+    let token = Address::generate(&env);
+    let guarantor = Address::generate(&env);
+    let collateral = vec![
+        &env,
+        Collateral { asset: CollateralRef::OnChain(token.clone()), amount: 500_000, value: 500_000 },
+        Collateral { asset: CollateralRef::OffChain(sha256(&env, "matricula 12345")), amount: 1, value: 800_000 },
+    ];
+    env.ledger().with_mut(|li| li.timestamp = 1678890000);
+    client.set_guarantee(&isin, &GuaranteeType::Real, &collateral, &1_300_000_000, &vec![&env, guarantor.clone()]);
+
+    let guarantee = client.guarantee(&isin).unwrap();
+    assert_eq!(guarantee.guarantee_type, GuaranteeType::Real);
+    assert_eq!(guarantee.collateral.get(0).unwrap().asset, CollateralRef::OnChain(token));
+    assert_eq!(guarantee.coverage_ratio, 1_300_000_000);
+    assert_eq!(guarantee.guarantors, vec![&env, guarantor]);
+    assert_eq!(guarantee.updated_at, 1678890000);
+
+    let invalid = [
+        (GuaranteeType::Real, vec![&env]),
+        (GuaranteeType::Quirografaria, collateral.clone()),
+        (GuaranteeType::Flutuante, vec![&env, Collateral { asset: CollateralRef::OffChain(sha256(&env, "estoque")), amount: 0, value: 1 }]),
+    ];
+    for (guarantee_type, collateral) in invalid {
+        let result = client.try_set_guarantee(&isin, &guarantee_type, &collateral, &1_000_000_000, &vec![&env]);
+        assert_eq!(result, Err(Ok(Error::InvalidGuarantee.into())));
+    }
+
+    let mut guarantors = vec![&env];
+    for _ in 0..MAX_GUARANTORS {
+        guarantors.push_back(Address::generate(&env));
+    }
+    client.set_guarantee(&isin, &GuaranteeType::Quirografaria, &vec![&env], &0, &guarantors);
+    guarantors.push_back(Address::generate(&env));
+    let result = client.try_set_guarantee(&isin, &GuaranteeType::Quirografaria, &vec![&env], &0, &guarantors);
+    assert_eq!(result, Err(Ok(Error::InvalidGuarantee.into())));
+}
+
+#[test]
+fn test_only_trustee_sets_guarantee() {
+    let env = Env::default();
+    let (contract_id, client, manager) = setup(&env);
+    let isin = String::from_str(&env, ISIN);
+    let trustee = Address::generate(&env);
+    env.mock_all_auths();
+    client.set_trustee(&isin, &trustee);
+
+    // Not even the series manager can register the guarantee
+    let collateral: Vec<Collateral> = vec![&env];
+    let guarantors: Vec<Address> = vec![&env];
+    for signer in [&manager, &trustee] {
+        env.mock_auths(&[
+            MockAuth {
+                address: signer,
+                invoke: &MockAuthInvoke {
+                    contract: &contract_id,
+                    fn_name: "set_guarantee",
+                    args: (&isin, GuaranteeType::Quirografaria, &collateral, 0i128, &guarantors).into_val(&env),
+                    sub_invokes: &[]
+                }
+            }
+        ]);
+        let result = client.try_set_guarantee(&isin, &GuaranteeType::Quirografaria, &collateral, &0, &guarantors);
+        assert_eq!(result.is_ok(), signer == &trustee);
+    }
+}
@@ -0,0 +1,120 @@
+use soroban_sdk::String;
+
+// -------------------------
+//  Identifier validation
+// -------------------------
+//
+// Soroban strings live in the host, so each check copies the identifier into a fixed
+// buffer first; anything longer than the expected length is rejected before the copy.
+
+pub const ISIN_LEN: usize = 12;
+pub const CNPJ_LEN: usize = 14;
+pub const LEI_LEN: usize = 20;
+
+fn copy_exact<const N: usize>(value: &String) -> Option<[u8; N]> {
+    if value.len() as usize != N {
+        return None;
+    }
+    let mut buf = [0u8; N];
+    value.copy_into_slice(&mut buf);
+    Some(buf)
+}
+
+// Digits map to 0-9 and uppercase letters to 10-35 (ISO 6166 / ISO 17442)
+fn alnum_value(c: u8) -> Option<u32> {
+    match c {
+        b'0'..=b'9' => Some((c - b'0') as u32),
+        b'A'..=b'Z' => Some((c - b'A') as u32 + 10),
+        _ => None,
+    }
+}
+
+// ISIN (ISO 6166): 2-letter country code, 9 alphanumerics and a Luhn check digit
+// computed over the letter-expanded digits
+pub fn is_valid_isin(isin: &String) -> bool {
+    let Some(chars) = copy_exact::<ISIN_LEN>(isin) else {
+        return false;
+    };
+    if !chars[..2].iter().all(u8::is_ascii_uppercase) || !chars[ISIN_LEN - 1].is_ascii_digit() {
+        return false;
+    }
+
+    // Each letter expands to two digits, so at most 2 * 11 + 1 digits
+    let mut digits = [0u8; 2 * ISIN_LEN];
+    let mut count = 0;
+    for &c in chars.iter() {
+        let Some(value) = alnum_value(c) else {
+            return false;
+        };
+        if value >= 10 {
+            digits[count] = (value / 10) as u8;
+            count += 1;
+        }
+        digits[count] = (value % 10) as u8;
+        count += 1;
+    }
+
+    // Luhn: double every second digit starting from the one left of the check digit
+    let mut sum = 0u32;
+    for (position, &digit) in digits[..count].iter().rev().enumerate() {
+        let mut d = digit as u32;
+        if !position.is_multiple_of(2) {
+            d *= 2;
+            if d > 9 {
+                d -= 9;
+            }
+        }
+        sum += d;
+    }
+    sum.is_multiple_of(10)
+}
+
+// CNPJ, unformatted: 12 characters (digits, or uppercase letters in the alphanumeric
+// format issued since July 2026) followed by the two numeric Receita Federal mod-11 check
+// digits. Each character counts as its ASCII code minus 48, so digits keep their value.
+pub fn is_valid_cnpj(cnpj: &String) -> bool {
+    let Some(chars) = copy_exact::<CNPJ_LEN>(cnpj) else {
+        return false;
+    };
+    let base_valid = chars[..CNPJ_LEN - 2].iter().all(|c| c.is_ascii_digit() || c.is_ascii_uppercase());
+    if !base_valid || !chars[CNPJ_LEN - 2..].iter().all(u8::is_ascii_digit) {
+        return false;
+    }
+    // Repeated digits ("00000000000000", ...) pass the arithmetic but are not real CNPJs
+    if chars.iter().all(|&c| c == chars[0]) {
+        return false;
+    }
+
+    const WEIGHTS: [u32; 13] = [6, 5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+    let check_digit = |len: usize| -> u8 {
+        let sum: u32 = chars[..len]
+            .iter()
+            .zip(WEIGHTS[WEIGHTS.len() - len..].iter())
+            .map(|(&c, &w)| (c - b'0') as u32 * w)
+            .sum();
+        let rest = sum % 11;
+        if rest < 2 { 0 } else { (11 - rest) as u8 }
+    };
+
+    check_digit(12) == chars[12] - b'0' && check_digit(13) == chars[13] - b'0'
+}
+
+// LEI (ISO 17442): 18 alphanumerics and 2 check digits; the letter-expanded number
+// must be congruent to 1 mod 97 (ISO 7064 MOD 97-10)
+pub fn is_valid_lei(lei: &String) -> bool {
+    let Some(chars) = copy_exact::<LEI_LEN>(lei) else {
+        return false;
+    };
+    if !chars[LEI_LEN - 2..].iter().all(u8::is_ascii_digit) {
+        return false;
+    }
+
+    let mut rest = 0u32;
+    for &c in chars.iter() {
+        let Some(value) = alnum_value(c) else {
+            return false;
+        };
+        rest = if value >= 10 { rest * 100 + value } else { rest * 10 + value } % 97;
+    }
+    rest == 1
+}
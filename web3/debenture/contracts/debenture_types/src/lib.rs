@@ -0,0 +1,146 @@
+#![no_std]
+
+// Types shared by the debenture contracts, and clients for calling them.
+//
+// A contract crate linked into another as a library also links its `#[contractimpl]`
+// exports, so token_minting.wasm would carry bond_storage's and token_custody's entry
+// points (and clash on names like `initialize`). Contracts that call each other depend
+// on this crate instead, which exports no functions.
+
+use soroban_sdk::{contractclient, contracttype, Address, Env, String, Vec};
+
+#[cfg(feature = "testutils")]
+pub mod testutils;
+
+// Most bond ids returned by one page of a flat holdings view
+pub const MAX_BOND_IDS_PAGE: u32 = 100;
+
+// Most ranges returned by one page of a ranges view
+pub const MAX_RANGES_PAGE: u32 = 50;
+
+// Fixed-point scale of rates and percentages: RATE_SCALE is 1.0 (100%), so 1.5% a.a. is
+// 15_000_000 and 110% do CDI is 1_100_000_000
+pub const RATE_SCALE: i128 = 1_000_000_000;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bond {
+    pub currency: Address,
+    pub denomination: i128,
+    pub remuneration: Remuneration,
+    pub frequency: u64,
+    pub issue_number: u64,
+    pub issue_date: u64,
+    pub maturity_date: u64,
+    pub bond_status: BondStatus,
+}
+
+// Reference rate the coupon is tied to
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Indexer {
+    // Taxa fixa: the coupon is `spread` alone
+    Prefixado,
+    Cdi,
+    Ipca,
+}
+
+// How accrual periods are counted when turning the annual rate into a coupon
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DayCount {
+    // Dias úteis / 252, the convention for CDI-linked debentures
+    Du252,
+    Thirty360,
+    Act365,
+}
+
+// Annual remuneration as `index_percentage` of the indexer plus `spread`, both scaled by
+// RATE_SCALE. "CDI + 1.5%" is (Cdi, RATE_SCALE, 15_000_000), "110% do CDI" is
+// (Cdi, 1_100_000_000, 0) and "prefixado 12%" is (Prefixado, 0, 120_000_000).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Remuneration {
+    pub indexer: Indexer,
+    pub index_percentage: i128,
+    pub spread: i128,
+    pub day_count: DayCount,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct IssueData {
+    pub investor: Address,
+    pub principal: u64,
+}
+
+// Lifecycle of a series. Registered -> Offered -> Issued -> Matured -> Redeemed, and only
+// through bond_storage's transition functions; `set_bond` keeps whatever status is stored.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BondStatus {
+    Registered,
+    Offered,
+    Issued,
+    Matured,
+    Redeemed,
+}
+
+// `quantity` consecutive bond ids starting at `first_id`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondRange {
+    pub first_id: u64,
+    pub quantity: u64,
+}
+
+// Returns up to `limit` (capped at MAX_RANGES_PAGE) of the `count` ranges of a holding,
+// read with `range_at`, starting at the `start`-th one
+pub fn ranges_page(env: &Env, count: u32, range_at: impl Fn(u32) -> BondRange, start: u32, limit: u32) -> Vec<BondRange> {
+    let end = count.min(start.saturating_add(limit.min(MAX_RANGES_PAGE)));
+    let mut page = Vec::new(env);
+    for index in start..end {
+        page.push_back(range_at(index));
+    }
+    page
+}
+
+// Expands the same holding into ids `start..start + limit` (limit capped at
+// MAX_BOND_IDS_PAGE) of the flat list. Ranges before the page are skipped whole, so the
+// cost depends on the number of ranges and the page size, not on how many bonds are held.
+pub fn bond_ids_page(env: &Env, count: u32, range_at: impl Fn(u32) -> BondRange, start: u32, limit: u32) -> Vec<u64> {
+    let mut skip = start as u64;
+    let mut remaining = limit.min(MAX_BOND_IDS_PAGE) as u64;
+    let mut page = Vec::new(env);
+    for index in 0..count {
+        if remaining == 0 {
+            break;
+        }
+        let range = range_at(index);
+        if skip >= range.quantity {
+            skip -= range.quantity;
+            continue;
+        }
+        let first = range.first_id + skip;
+        let take = (range.quantity - skip).min(remaining);
+        for bond_id in first..first + take {
+            page.push_back(bond_id);
+        }
+        skip = 0;
+        remaining -= take;
+    }
+    page
+}
+
+// The part of bond_storage other contracts read
+#[contractclient(name = "BondStorageClient")]
+pub trait BondStorageInterface {
+    fn bond_info(env: Env, isin: String) -> Bond;
+    fn bond_status(env: Env, isin: String) -> BondStatus;
+}
+
+// The part of token_custody token_minting calls
+#[contractclient(name = "TokenCustodyClient")]
+pub trait TokenCustodyInterface {
+    fn deposit_lot(env: Env, user: Address, first_id: u64, quantity: u64);
+}
@@ -0,0 +1,46 @@
+// Cost metering shared by the contracts' tests. Each cost test seeds state, meters one
+// call with `measure`, and compares the footprints of a small and a large setup.
+
+use soroban_sdk::Env;
+
+// Mainnet per-transaction limits every metered call must fit in
+pub const TX_MAX_INSTRUCTIONS: i64 = 100_000_000;
+pub const TX_MAX_READ_ENTRIES: u32 = 100;
+pub const TX_MAX_WRITE_ENTRIES: u32 = 50;
+pub const TX_MAX_WRITE_BYTES: u32 = 132_096;
+pub const TX_MAX_EVENTS_SIZE_BYTES: u32 = 16_384;
+
+// Ledger footprint of one invocation. CPU is only checked against the limit, not compared
+// between setups: the test host keeps one recording footprint across invocations, so its
+// bookkeeping grows with every entry ever touched in the test, which does not happen
+// on-chain.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Footprint {
+    pub read_entries: u32,
+    pub write_entries: u32,
+    pub write_bytes: u32,
+    pub events_bytes: u32,
+    // Rent paid for TTL extensions of persistent entries, in ledger-bytes
+    pub rent_ledger_bytes: i64,
+}
+
+// Runs `invoke` on a fresh budget and returns its footprint. Fails if the call would not
+// fit in a single transaction.
+pub fn measure(env: &Env, invoke: impl FnOnce()) -> Footprint {
+    env.cost_estimate().budget().reset_default();
+    invoke();
+    let resources = env.cost_estimate().resources();
+    let footprint = Footprint {
+        read_entries: resources.memory_read_entries + resources.disk_read_entries,
+        write_entries: resources.write_entries,
+        write_bytes: resources.write_bytes,
+        events_bytes: resources.contract_events_size_bytes,
+        rent_ledger_bytes: resources.persistent_rent_ledger_bytes,
+    };
+    assert!(resources.instructions <= TX_MAX_INSTRUCTIONS, "{} instructions", resources.instructions);
+    assert!(footprint.read_entries <= TX_MAX_READ_ENTRIES, "{footprint:?}");
+    assert!(footprint.write_entries <= TX_MAX_WRITE_ENTRIES, "{footprint:?}");
+    assert!(footprint.write_bytes <= TX_MAX_WRITE_BYTES, "{footprint:?}");
+    assert!(footprint.events_bytes <= TX_MAX_EVENTS_SIZE_BYTES, "{footprint:?}");
+    footprint
+}
@@ -1,9 +1,14 @@
 use soroban_sdk::{contract, contractimpl, contracttype, Env, Address, Vec};
+pub use debenture_types::BondRange;
+use debenture_types::{bond_ids_page, ranges_page};
 
+// A custody position is `RangeCount(user)` entries `Range(user, index)`, so a deposit
+// writes one small entry however many ranges the position already has
 #[contracttype]
 pub enum DataKey {
     Count(Address),
-    Bonds(Address),
+    RangeCount(Address),
+    Range(Address, u32),
 }
 
 #[contract]
@@ -12,27 +17,57 @@ pub struct TokenCustody;
 #[contractimpl]
 impl TokenCustody {
     pub fn deposit_bond(env: Env, user: Address, bond_id: u64) {
+        Self::deposit_lot(env, user, bond_id, 1);
+    }
+
+    // Deposits bonds `first_id..first_id + quantity` with a constant number of writes
+    pub fn deposit_lot(env: Env, user: Address, first_id: u64, quantity: u64) {
         user.require_auth();
 
         // Incrementa totalValueLocked
         let count_key = DataKey::Count(user.clone());
-        let count: u64 = env.storage().instance().get(&count_key).unwrap_or(0);
-        env.storage().instance().set(&count_key, &(count + 1));
-
-        // Adiciona bond na posição de custódia
-        let bonds_key = DataKey::Bonds(user);
-        let mut bonds: Vec<u64> = env.storage().instance().get(&bonds_key).unwrap_or_else(|| Vec::new(&env));
-        bonds.push_back(bond_id);
-        env.storage().instance().set(&bonds_key, &bonds);
+        let count: u64 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        env.storage().persistent().set(&count_key, &(count + quantity));
+
+        // Adiciona bonds na posição de custódia, estendendo a última faixa quando contígua
+        let range_count = Self::range_count(&env, &user);
+        if range_count > 0 {
+            let last_key = DataKey::Range(user.clone(), range_count - 1);
+            let mut last: BondRange = env.storage().persistent().get(&last_key).unwrap();
+            if last.first_id + last.quantity == first_id {
+                last.quantity += quantity;
+                env.storage().persistent().set(&last_key, &last);
+                return;
+            }
+        }
+        env.storage().persistent().set(&DataKey::Range(user.clone(), range_count), &BondRange { first_id, quantity });
+        env.storage().persistent().set(&DataKey::RangeCount(user), &(range_count + 1));
+    }
+
+    fn range_count(env: &Env, user: &Address) -> u32 {
+        env.storage().persistent().get(&DataKey::RangeCount(user.clone())).unwrap_or(0)
+    }
+
+    fn range_at(env: &Env, user: &Address, index: u32) -> BondRange {
+        env.storage().persistent().get(&DataKey::Range(user.clone(), index)).unwrap()
     }
 
     pub fn get_total_bonds_deposited(env: Env, user: Address) -> u64 {
         let key = DataKey::Count(user);
-        env.storage().instance().get(&key).unwrap_or(0)
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    // Up to `limit` ids of the custody position (capped at MAX_BOND_IDS_PAGE), starting
+    // at the `start`-th one
+    pub fn get_bonds_position_custody(env: Env, user: Address, start: u32, limit: u32) -> Vec<u64> {
+        let count = Self::range_count(&env, &user);
+        bond_ids_page(&env, count, |index| Self::range_at(&env, &user, index), start, limit)
     }
 
-    pub fn get_bonds_position_custody(env: Env, user: Address) -> Vec<u64> {
-        let key = DataKey::Bonds(user);
-        env.storage().instance().get(&key).unwrap_or_else(|| Vec::new(&env))
+    // Up to `limit` ranges of consecutive ids of the same position (capped at
+    // MAX_RANGES_PAGE), starting at the `start`-th one
+    pub fn get_bond_ranges_custody(env: Env, user: Address, start: u32, limit: u32) -> Vec<BondRange> {
+        let count = Self::range_count(&env, &user);
+        ranges_page(&env, count, |index| Self::range_at(&env, &user, index), start, limit)
     }
 }
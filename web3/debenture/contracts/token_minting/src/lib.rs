@@ -1,2 +1,5 @@
 #![no_std]
 pub mod token_minting;
+
+#[cfg(test)]
+pub mod test;
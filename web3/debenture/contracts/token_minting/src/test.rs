@@ -0,0 +1,342 @@
+#![cfg(test)]
+
+extern crate std;
+
+use super::token_minting::{TokenMintingContract, TokenMintingContractClient};
+use bond_storage::bond_storage::{
+    Bond, BondStatus, BondStorage, BondStorageClient, DayCount, Indexer, Issuer, Remuneration, RATE_SCALE,
+};
+use token_custody::token_custody::{BondRange, TokenCustody, TokenCustodyClient};
+use debenture_types::testutils::{measure, Footprint};
+use debenture_types::MAX_BOND_IDS_PAGE;
+use soroban_sdk::testutils::{Address as _, EnvTestConfig, Ledger, MockAuth, MockAuthInvoke};
+use soroban_sdk::{vec, Address, Env, Error, IntoVal, String, Symbol};
+
+const ISIN: &str = "BREMISDEB5H1";
+const MATURITY_DATE: u64 = 1710499200;
+
+struct Setup<'a> {
+    minting: TokenMintingContractClient<'a>,
+    storage: BondStorageClient<'a>,
+    custody: TokenCustodyClient<'a>,
+    isin: String,
+    currency: Address,
+}
+
+// Registers the series in bond_storage, opens its offering and wires token_minting to it
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1678886400);
+    let isin = String::from_str(env, ISIN);
+    let currency = Address::generate(env);
+
+    let storage = BondStorageClient::new(env, &env.register(BondStorage, (Address::generate(env),)));
+    let bond = Bond {
+        currency: currency.clone(),
+        denomination: 1000,
+        remuneration: Remuneration {
+            indexer: Indexer::Cdi,
+            index_percentage: RATE_SCALE,
+            spread: 15_000_000,
+            day_count: DayCount::Du252,
+        },
+        frequency: 2,
+        issue_number: 1,
+        issue_date: 1678886400,
+        maturity_date: MATURITY_DATE,
+        bond_status: BondStatus::Registered,
+    };
+    let issuer = Issuer {
+        institution: Address::generate(env),
+        cnpj: String::from_str(env, "11222333000181"),
+        lei: String::from_str(env, "5493001KJTIIGC8Y1R12"),
+    };
+    storage.register_series(&isin, &bond, &issuer);
+    storage.open_offering(&isin);
+
+    let custody = TokenCustodyClient::new(env, &env.register(TokenCustody, ()));
+    let minting_id = env.register(TokenMintingContract, (&custody.address, &storage.address, &isin));
+    let minting = TokenMintingContractClient::new(env, &minting_id);
+
+    Setup { minting, storage, custody, isin, currency }
+}
+
+#[test]
+fn test_mint_reads_terms_from_bond_storage() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let investor = Address::generate(&env);
+
+    setup.minting.mint_tokens(&investor, &3);
+    assert_eq!(setup.minting.get_investor_bonds(&investor, &0, &100), vec![&env, 1, 2, 3]);
+    assert_eq!(setup.minting.issue_volume(), 3);
+    assert_eq!(setup.custody.get_total_bonds_deposited(&investor), 3);
+
+    let bond = setup.minting.get_bond_details(&2);
+    assert_eq!(bond.currency, setup.currency);
+    assert_eq!(bond.denomination, 1000);
+    assert_eq!(bond.remuneration, setup.storage.remuneration(&setup.isin));
+    assert_eq!(bond.maturity_date, MATURITY_DATE);
+    assert_eq!(bond.issue_number, 2);
+    assert_eq!(bond.bond_status, BondStatus::Offered);
+
+    // Changes to the series terms reach the next subscription
+    setup.storage.set_bond(&setup.isin, &Bond { denomination: 2000, ..setup.storage.bond_info(&setup.isin) });
+    setup.minting.mint_tokens(&investor, &1);
+    assert_eq!(setup.minting.get_bond_details(&4).denomination, 2000);
+}
+
+#[test]
+fn test_status_follows_bond_storage() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let investor = Address::generate(&env);
+    assert_eq!(setup.minting.bond_status(), BondStatus::Offered);
+    setup.minting.mint_tokens(&investor, &1);
+
+    setup.storage.close_offering(&setup.isin, &1000);
+    assert_eq!(setup.minting.bond_status(), BondStatus::Issued);
+    assert_eq!(setup.minting.get_bond_details(&1).bond_status, BondStatus::Issued);
+
+    env.ledger().with_mut(|li| li.timestamp = MATURITY_DATE);
+    setup.storage.mark_matured(&setup.isin);
+    assert_eq!(setup.minting.bond_status(), BondStatus::Matured);
+    assert_eq!(setup.minting.get_bond_details(&1).bond_status, BondStatus::Matured);
+
+    setup.storage.record_principal_payment(&setup.isin, &1000);
+    setup.storage.mark_redeemed(&setup.isin);
+    assert_eq!(setup.minting.bond_status(), BondStatus::Redeemed);
+}
+
+#[test]
+#[should_panic(expected = "OFFERING_NOT_OPEN")]
+fn test_mint_requires_open_offering() {
+    let env = Env::default();
+    let setup = setup(&env);
+    setup.storage.close_offering(&setup.isin, &0);
+
+    setup.minting.mint_tokens(&Address::generate(&env), &1);
+}
+
+#[test]
+fn test_transfer_by_owner_and_operator() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let operator = Address::generate(&env);
+    setup.minting.mint_tokens(&owner, &2);
+
+    env.mock_auths(&[MockAuth {
+        address: &owner,
+        invoke: &MockAuthInvoke {
+            contract: &setup.minting.address,
+            fn_name: "transfer_bond_ownership",
+            args: (1u64, &buyer).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    setup.minting.transfer_bond_ownership(&1, &buyer);
+    assert_eq!(setup.minting.get_investor_by_bond_id(&1), buyer);
+
+    env.mock_all_auths();
+    setup.minting.set_operator(&owner, &operator, &true);
+    assert!(setup.minting.is_operator(&owner, &operator));
+    env.mock_auths(&[MockAuth {
+        address: &operator,
+        invoke: &MockAuthInvoke {
+            contract: &setup.minting.address,
+            fn_name: "operator_transfer",
+            args: (&operator, 2u64, &buyer).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    setup.minting.operator_transfer(&operator, &2, &buyer);
+    assert_eq!(setup.minting.get_investor_bonds(&buyer, &0, &100), vec![&env, 1, 2]);
+    assert_eq!(setup.minting.get_investor_bonds(&owner, &0, &100), vec![&env]);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn test_unauthorized_transfer_bond_ownership() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let owner = Address::generate(&env);
+    setup.minting.mint_tokens(&owner, &1);
+    env.set_auths(&[]);
+
+    setup.minting.transfer_bond_ownership(&1, &Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn test_transfer_signed_by_someone_else() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let owner = Address::generate(&env);
+    let thief = Address::generate(&env);
+    setup.minting.mint_tokens(&owner, &1);
+
+    env.mock_auths(&[MockAuth {
+        address: &thief,
+        invoke: &MockAuthInvoke {
+            contract: &setup.minting.address,
+            fn_name: "transfer_bond_ownership",
+            args: (1u64, &thief).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    setup.minting.transfer_bond_ownership(&1, &thief);
+}
+
+#[test]
+#[should_panic(expected = "NOT_AN_OPERATOR")]
+fn test_operator_transfer_requires_approval() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let owner = Address::generate(&env);
+    let operator = Address::generate(&env);
+    setup.minting.mint_tokens(&owner, &1);
+
+    setup.minting.operator_transfer(&operator, &1, &operator);
+}
+
+#[test]
+fn test_constructor_sets_series() {
+    let env = Env::default();
+    let setup = setup(&env);
+    assert_eq!(setup.minting.bond_status(), BondStatus::Offered);
+
+    // Configuration is fixed at deploy time: the constructor cannot re-point it
+    let args = vec![
+        &env,
+        setup.custody.address.into_val(&env),
+        Address::generate(&env).into_val(&env),
+        setup.isin.into_val(&env),
+    ];
+    let result = env.try_invoke_contract::<(), Error>(&setup.minting.address, &Symbol::new(&env, "__constructor"), args);
+    assert!(result.is_err());
+    assert_eq!(setup.minting.bond_status(), BondStatus::Offered);
+}
+
+#[test]
+fn test_holdings_equivalent_after_transfers() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    setup.minting.mint_tokens(&alice, &5);
+    env.ledger().with_mut(|li| li.timestamp = 1678890000);
+    setup.minting.mint_tokens(&bob, &3);
+    setup.minting.mint_tokens(&alice, &2);
+
+    setup.minting.transfer_bond_ownership(&3, &bob);
+    setup.minting.transfer_bond_ownership(&2, &bob);
+    setup.minting.transfer_bond_ownership(&7, &alice);
+
+    // Same bonds as with one record per unit. Arrivals append; taking 3 out of 1..=5 left
+    // 1..=2 in place and moved 4..=5 to the end
+    assert_eq!(setup.minting.get_investor_bonds(&alice, &0, &100), vec![&env, 1, 9, 10, 4, 5, 7]);
+    assert_eq!(setup.minting.get_investor_bonds(&bob, &0, &100), vec![&env, 6, 3, 2, 8]);
+    let ranges = vec![
+        &env,
+        BondRange { first_id: 1, quantity: 1 },
+        BondRange { first_id: 9, quantity: 2 },
+        BondRange { first_id: 4, quantity: 2 },
+        BondRange { first_id: 7, quantity: 1 },
+    ];
+    assert_eq!(setup.minting.get_investor_ranges(&alice, &0, &10), ranges);
+    assert_eq!(setup.minting.get_investor_ranges(&alice, &1, &2), ranges.slice(1..3));
+    assert_eq!(setup.minting.get_investor_by_bond_id(&3), bob);
+    assert_eq!(setup.minting.get_investor_by_bond_id(&7), alice);
+    assert_eq!(setup.minting.get_investor_by_bond_id(&10), alice);
+
+    assert_eq!(setup.minting.get_bond_details(&5).issue_number, 5);
+    assert_eq!(setup.minting.get_bond_details(&5).issue_date, 1678886400);
+    assert_eq!(setup.minting.get_bond_details(&7).issue_date, 1678890000);
+    assert_eq!(setup.custody.get_bonds_position_custody(&alice, &0, &100), vec![&env, 1, 2, 3, 4, 5, 9, 10]);
+    assert!(setup.minting.try_get_bond_details(&11).is_err());
+
+    // Pages cut across ranges
+    assert_eq!(setup.minting.get_investor_bonds(&alice, &2, &3), vec![&env, 10, 4, 5]);
+    assert_eq!(setup.minting.get_investor_bonds(&alice, &5, &3), vec![&env, 7]);
+    assert_eq!(setup.minting.get_investor_bonds(&alice, &6, &3), vec![&env]);
+    assert_eq!(setup.custody.get_bonds_position_custody(&alice, &4, &2), vec![&env, 5, 9]);
+
+    // Emptying a range moves the last one into its slot
+    setup.minting.transfer_bond_ownership(&1, &bob);
+    assert_eq!(setup.minting.get_investor_bonds(&alice, &0, &100), vec![&env, 7, 9, 10, 4, 5]);
+}
+
+// A holding of the whole 40,000-bond supply stays queryable: the range views return one
+// range, and each flat page only expands the ids it returns. Expanding every id in one
+// call exceeds the budget from about 4,000 bonds.
+#[test]
+fn test_views_of_full_supply_holding() {
+    let env = Env::new_with_config(EnvTestConfig { capture_snapshot_at_drop: false });
+    let setup = setup(&env);
+    let whale = Address::generate(&env);
+    setup.minting.mint_tokens(&whale, &40_000);
+
+    let whole = vec![&env, BondRange { first_id: 1, quantity: 40_000 }];
+    assert_eq!(setup.minting.get_investor_ranges(&whale, &0, &10), whole);
+    assert_eq!(setup.custody.get_bond_ranges_custody(&whale, &0, &10), whole);
+
+    let page = setup.minting.get_investor_bonds(&whale, &39_950, &MAX_BOND_IDS_PAGE);
+    assert_eq!(page.len(), 50);
+    assert_eq!(page.first(), Some(39_951));
+    assert_eq!(page.last(), Some(40_000));
+
+    let page = setup.custody.get_bonds_position_custody(&whale, &0, &1_000);
+    assert_eq!(page.len(), MAX_BOND_IDS_PAGE);
+    assert_eq!(page.last(), Some(MAX_BOND_IDS_PAGE as u64));
+}
+
+// Meters a subscription of `quantity` bonds by a fresh investor, after an earlier one
+fn mint_cost(quantity: u64) -> Footprint {
+    let env = Env::new_with_config(EnvTestConfig { capture_snapshot_at_drop: false });
+    let setup = setup(&env);
+    setup.minting.mint_tokens(&Address::generate(&env), &10);
+
+    let investor = Address::generate(&env);
+    measure(&env, || setup.minting.mint_tokens(&investor, &quantity))
+}
+
+// Subscriptions write one lot whatever their size, so buying the whole default supply
+// in one call costs the same as buying a single bond. With one record per unit, 100
+// bonds took 206 writes and 400 bonds over 345M instructions. Now a subscription writes
+// eight entries here (the lot and its count, the investor's range and range count, issue
+// data, next id, remaining supply and the payer's auth nonce) and three in custody.
+#[test]
+fn test_mint_cost_flat_in_quantity() {
+    let cost = mint_cost(39_990);
+    assert_eq!(mint_cost(1), cost);
+    assert_eq!(cost.write_entries, 11);
+}
+
+// Meters a transfer out of the middle of the first of `ranges` ranges an owner holds
+fn transfer_cost(ranges: u32) -> Footprint {
+    let env = Env::new_with_config(EnvTestConfig { capture_snapshot_at_drop: false });
+    let setup = setup(&env);
+    let owner = Address::generate(&env);
+    for _ in 0..ranges {
+        setup.minting.mint_tokens(&owner, &3);
+        setup.minting.mint_tokens(&Address::generate(&env), &1);
+    }
+    assert_eq!(setup.minting.get_investor_ranges(&owner, &0, &100).len(), ranges);
+
+    let buyer = Address::generate(&env);
+    measure(&env, || setup.minting.transfer_bond_ownership(&2, &buyer))
+}
+
+// Each range is its own entry, so splitting one writes the same entries however many the
+// owner holds: the bond's owner, the owner's split range, its moved tail and range count,
+// the buyer's range and range count, and the owner's auth nonce. Finding the range still
+// reads the ranges before it.
+#[test]
+fn test_transfer_writes_flat_as_ranges_grow() {
+    let (few, many) = (transfer_cost(1), transfer_cost(40));
+    assert_eq!((few.write_entries, few.write_bytes), (many.write_entries, many.write_bytes));
+    assert_eq!(few.write_entries, 7);
+}
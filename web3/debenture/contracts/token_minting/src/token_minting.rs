@@ -1,40 +1,33 @@
-use soroban_sdk::{contract, contractimpl, contracttype, Env, Address, Vec, Symbol, vec, IntoVal, String};
+use soroban_sdk::{contract, contractevent, contractimpl, contracttype, Env, Address, String, Vec, Symbol};
+use debenture_types::{bond_ids_page, ranges_page, Bond, BondRange, BondStatus, BondStorageClient, IssueData, TokenCustodyClient};
 
 // Struct definition
 #[contracttype]
 #[derive(Clone)]
-pub struct Bond {
-    pub currency: Address,
-    pub denomination: i128,
-    pub interest_rate: i128,
-    pub frequency: u64,
-    pub issue_number: u64,
-    pub issue_date: u64,
-    pub maturity_date: u64,
-    pub bond_status: BondStatus,
+pub struct TokenMinting {
+    custody_contract: Address,
+    max_total_supply: u64,
 }
 
+// Bonds `first_id..first_id + quantity` bought in one subscription, all sharing the terms
+// in `bond` (whose `issue_number` is `first_id`). Lots are never split: transfers only
+// change the owner of individual ids.
 #[contracttype]
 #[derive(Clone)]
-pub struct IssueData {
+pub struct Lot {
+    pub first_id: u64,
+    pub quantity: u64,
     pub investor: Address,
-    pub principal: u64,
-}
-
-#[contracttype]
-#[derive(Clone, Copy)]
-pub enum BondStatus {
-    Offered,
-    Issued,
-    Matured,
-    Redeemed,
+    pub bond: Bond,
 }
 
-#[contracttype]
-#[derive(Clone)]
-pub struct TokenMinting {
-    custody_contract: Address,
-    max_total_supply: u64,
+// topics ["BondsIssued", investor], data [first_id, quantity]
+#[contractevent(topics = ["BondsIssued"], data_format = "vec")]
+pub struct BondsIssued {
+    #[topic]
+    pub investor: Address,
+    pub first_id: u64,
+    pub quantity: u64,
 }
 
 #[contract]
@@ -42,13 +35,39 @@ pub struct TokenMintingContract;
 
 #[contractimpl]
 impl TokenMintingContract {
-    pub fn initialize(env: Env, custody_contract: Address) {
+    // Set at deploy time and never again, so nobody can claim a fresh deployment before
+    // its owner. `bond_storage`/`isin` identify the series being distributed; its terms
+    // and status are read from there
+    pub fn __constructor(env: Env, custody_contract: Address, bond_storage: Address, isin: String) {
         env.storage().instance().set(&Symbol::new(&env, "custody_contract"), &custody_contract);
+        env.storage().instance().set(&Symbol::new(&env, "bond_storage"), &bond_storage);
+        env.storage().instance().set(&Symbol::new(&env, "isin"), &isin);
     }
-    
+
+    // The series being distributed: its bond_storage registry and ISIN
+    fn series(env: &Env) -> (BondStorageClient<'_>, String) {
+        let bond_storage: Address = env.storage().instance().get(&Symbol::new(env, "bond_storage")).unwrap();
+        let isin: String = env.storage().instance().get(&Symbol::new(env, "isin")).unwrap();
+        (BondStorageClient::new(env, &bond_storage), isin)
+    }
+
+    // Terms of the series, as registered in bond_storage
+    fn bond_terms(env: &Env) -> Bond {
+        let (bond_storage, isin) = Self::series(env);
+        bond_storage.bond_info(&isin)
+    }
+
+    // Current lifecycle status of the series, as recorded in bond_storage
+    pub fn bond_status(env: Env) -> BondStatus {
+        let (bond_storage, isin) = Self::series(&env);
+        bond_storage.bond_status(&isin)
+    }
+
     pub fn mint_tokens(env: Env, payer: Address, number_of_debentures: u64) {
         payer.require_auth();
         assert!(number_of_debentures > 0, "Quantidade inválida");
+        let terms = Self::bond_terms(&env);
+        assert!(terms.bond_status == BondStatus::Offered, "OFFERING_NOT_OPEN");
         
         let mut max_supply: u64 = env.storage().persistent().get(&Symbol::new(&env, "max_total_supply")).unwrap_or(40000);
         assert!(number_of_debentures <= max_supply, "MAX_SUPPLY_EXCEEDED");
@@ -61,110 +80,197 @@ impl TokenMintingContract {
         let issue_data_key = (payer.clone(), Symbol::new(&env, "issue_data"));
         env.storage().persistent().set(&issue_data_key, &issue_data);
 
-        let mut issue_number: u64 = env.storage().persistent().get(&Symbol::new(&env, "issue_number")).unwrap_or(1);
+        // One lot per subscription, so the writes below do not depend on the quantity
+        let first_id: u64 = env.storage().persistent().get(&Symbol::new(&env, "issue_number")).unwrap_or(1);
+        env.storage().persistent().set(&Symbol::new(&env, "issue_number"), &(first_id + number_of_debentures));
 
-        for _ in 0..number_of_debentures {
-            let bond_id = issue_number;
-            issue_number += 1;
-            env.storage().persistent().set(&Symbol::new(&env, "issue_number"), &issue_number);
-
-            // get data BondStorage
-            let bond_template: Bond = env.storage().persistent().get(&Symbol::new(&env, "bond_template")).unwrap();
-
-            // Create new bond 
-            let bond = Bond {
-                currency: bond_template.currency.clone(),
-                denomination: bond_template.denomination,
-                interest_rate: bond_template.interest_rate,
-                frequency: bond_template.frequency,
-                issue_number: bond_id,
+        let lot_count: u32 = env.storage().persistent().get(&Symbol::new(&env, "lot_count")).unwrap_or(0);
+        let lot = Lot {
+            first_id,
+            quantity: number_of_debentures,
+            investor: payer.clone(),
+            bond: Bond {
+                issue_number: first_id,
                 issue_date: env.ledger().timestamp(),
-                maturity_date: env.ledger().timestamp() + 47260800, // 547 dias
                 bond_status: BondStatus::Issued,
-            };
-
-            let bond_key = (bond_id, Symbol::new(&env, "bond"));
-            env.storage().persistent().set(&bond_key, &bond);
+                ..terms
+            },
+        };
+        env.storage().persistent().set(&(lot_count, Symbol::new(&env, "lot")), &lot);
+        env.storage().persistent().set(&Symbol::new(&env, "lot_count"), &(lot_count + 1));
 
-            // Update investorBonds
-            let mut bonds: Vec<u64> = env.storage().persistent().get(&payer).unwrap_or(Vec::new(&env));
-            bonds.push_back(bond_id);
-            env.storage().persistent().set(&payer, &bonds);
+        // Update investorBonds
+        Self::add_holding(&env, &payer, first_id, number_of_debentures);
 
-            // Update bondToInvestor
-            let owner_key = (bond_id, Symbol::new(&env, "owner"));
-            env.storage().persistent().set(&owner_key, &payer);
+        // Reduce supply
+        max_supply -= number_of_debentures;
+        env.storage().persistent().set(&Symbol::new(&env, "max_total_supply"), &max_supply);
 
-            // Reduce supply
-            max_supply -= 1;
-            env.storage().persistent().set(&Symbol::new(&env, "max_total_supply"), &max_supply);
+        // Call TokenCustody
+        let custody_contract_address: Address = env.storage().instance()
+            .get(&Symbol::new(&env, "custody_contract")).unwrap();
 
-            // Call TokenCustody 
-            let custody_contract_address: Address = env.storage().instance()
-                .get(&Symbol::new(&env, "custody_contract")).unwrap();
+        TokenCustodyClient::new(&env, &custody_contract_address).deposit_lot(&payer, &first_id, &number_of_debentures);
 
-            let _: () = env.invoke_contract(
-                &custody_contract_address,
-                &Symbol::new(&env, "deposit_bond"),
-                vec![&env, payer.clone().into_val(&env), bond_id.into_val(&env)]
-            );
+        BondsIssued { investor: payer, first_id, quantity: number_of_debentures }.publish(&env);
+    }
 
-            env.events().publish((Symbol::new(&env, "BondIssued"),), bond_id);
+    // Lets `operator` transfer any of `owner`'s bonds, or revokes that permission
+    pub fn set_operator(env: Env, owner: Address, operator: Address, approved: bool) {
+        owner.require_auth();
+        let operator_key = (owner, operator, Symbol::new(&env, "operator"));
+        if approved {
+            env.storage().persistent().set(&operator_key, &true);
+        } else {
+            env.storage().persistent().remove(&operator_key);
         }
     }
 
+    pub fn is_operator(env: Env, owner: Address, operator: Address) -> bool {
+        env.storage().persistent().has(&(owner, operator, Symbol::new(&env, "operator")))
+    }
+
+    // Transfer signed by the current owner
     pub fn transfer_bond_ownership(env: Env, bond_id: u64, new_investor: Address) {
-        // Basic validation of investor
-        assert!(!new_investor.to_string().is_empty(), "Novo investidor inválido");
+        let current_owner = Self::get_investor_by_bond_id(env.clone(), bond_id);
+        current_owner.require_auth();
+        Self::move_bond(&env, bond_id, current_owner, new_investor);
+    }
 
-        let owner_key = (bond_id, Symbol::new(&env, "owner"));
-        let current_owner: Address = env.storage().persistent().get(&owner_key).unwrap();
-        
-        // Owner Validation
-        assert!(!current_owner.to_string().is_empty(), "Bond não possui dono");
+    // Transfer signed by an operator the current owner approved
+    pub fn operator_transfer(env: Env, operator: Address, bond_id: u64, new_investor: Address) {
+        operator.require_auth();
+        let current_owner = Self::get_investor_by_bond_id(env.clone(), bond_id);
+        assert!(Self::is_operator(env.clone(), current_owner.clone(), operator), "NOT_AN_OPERATOR");
+        Self::move_bond(&env, bond_id, current_owner, new_investor);
+    }
 
-        // Updaate new owner
+    fn move_bond(env: &Env, bond_id: u64, current_owner: Address, new_investor: Address) {
+        // Update new owner; it takes precedence over the investor of the bond's lot
+        let owner_key = (bond_id, Symbol::new(env, "owner"));
         env.storage().persistent().set(&owner_key, &new_investor);
 
-        // Remove bond of old investor
-        let old_bonds: Vec<u64> = env.storage().persistent().get(&current_owner).unwrap_or(Vec::new(&env));
-        
-        // Create new Vec filtering removed bond
-        let mut filtered_bonds = Vec::new(&env);
-        for bond in old_bonds.iter() {
-            if bond != bond_id {
-                filtered_bonds.push_back(bond);
+        // Remove bond of old investor, splitting the range that holds it: the part before
+        // the bond stays in place and the part after it goes to the end of the list
+        let count = Self::range_count(env, &current_owner);
+        let (index, range) = (0..count)
+            .map(|index| (index, Self::range_at(env, &current_owner, index)))
+            .find(|(_, range)| range.first_id <= bond_id && bond_id < range.first_id + range.quantity)
+            .unwrap();
+        let before = BondRange { first_id: range.first_id, quantity: bond_id - range.first_id };
+        let after = BondRange { first_id: bond_id + 1, quantity: range.first_id + range.quantity - bond_id - 1 };
+        match (before.quantity > 0, after.quantity > 0) {
+            (true, true) => {
+                Self::set_range(env, &current_owner, index, &before);
+                Self::push_range(env, &current_owner, count, &after);
+            }
+            (true, false) => Self::set_range(env, &current_owner, index, &before),
+            (false, true) => Self::set_range(env, &current_owner, index, &after),
+            (false, false) => {
+                // The last range takes the emptied slot
+                let last = count - 1;
+                if index != last {
+                    Self::set_range(env, &current_owner, index, &Self::range_at(env, &current_owner, last));
+                }
+                env.storage().persistent().remove(&Self::range_key(env, &current_owner, last));
+                env.storage().persistent().set(&Self::range_count_key(env, &current_owner), &last);
             }
         }
-        env.storage().persistent().set(&current_owner, &filtered_bonds);
 
         // Add to new investor
-        let mut new_bonds: Vec<u64> = env.storage().persistent().get(&new_investor).unwrap_or(Vec::new(&env));
-        new_bonds.push_back(bond_id);
-        env.storage().persistent().set(&new_investor, &new_bonds);
+        Self::add_holding(env, &new_investor, bond_id, 1);
+    }
+
+    // An investor's holdings are `range_count` entries `(investor, index, "range")`, so
+    // mints and transfers write a few small entries however many ranges are held
+    fn range_count_key(env: &Env, investor: &Address) -> (Address, Symbol) {
+        (investor.clone(), Symbol::new(env, "range_count"))
+    }
+
+    fn range_key(env: &Env, investor: &Address, index: u32) -> (Address, u32, Symbol) {
+        (investor.clone(), index, Symbol::new(env, "range"))
+    }
+
+    fn range_count(env: &Env, investor: &Address) -> u32 {
+        env.storage().persistent().get(&Self::range_count_key(env, investor)).unwrap_or(0)
+    }
+
+    fn range_at(env: &Env, investor: &Address, index: u32) -> BondRange {
+        env.storage().persistent().get(&Self::range_key(env, investor, index)).unwrap()
+    }
+
+    fn set_range(env: &Env, investor: &Address, index: u32, range: &BondRange) {
+        env.storage().persistent().set(&Self::range_key(env, investor, index), range);
     }
 
+    // Appends a range to the `count` an investor already holds
+    fn push_range(env: &Env, investor: &Address, count: u32, range: &BondRange) {
+        Self::set_range(env, investor, count, range);
+        env.storage().persistent().set(&Self::range_count_key(env, investor), &(count + 1));
+    }
+
+    // Appends bonds to an investor's holdings, extending the last range when contiguous
+    fn add_holding(env: &Env, investor: &Address, first_id: u64, quantity: u64) {
+        let count = Self::range_count(env, investor);
+        if count > 0 {
+            let mut last = Self::range_at(env, investor, count - 1);
+            if last.first_id + last.quantity == first_id {
+                last.quantity += quantity;
+                Self::set_range(env, investor, count - 1, &last);
+                return;
+            }
+        }
+        Self::push_range(env, investor, count, &BondRange { first_id, quantity });
+    }
+
+    // Lots are created with increasing ids, so the one holding `bond_id` is found by
+    // binary search over their indexes
+    fn find_lot(env: &Env, bond_id: u64) -> Lot {
+        let lot_count: u32 = env.storage().persistent().get(&Symbol::new(env, "lot_count")).unwrap_or(0);
+        let (mut low, mut high) = (0u32, lot_count);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let lot: Lot = env.storage().persistent().get(&(mid, Symbol::new(env, "lot"))).unwrap();
+            if bond_id < lot.first_id {
+                high = mid;
+            } else if bond_id >= lot.first_id + lot.quantity {
+                low = mid + 1;
+            } else {
+                return lot;
+            }
+        }
+        panic!("BOND_NOT_FOUND");
+    }
+
+    // Unit data as minted, with the series' current status
     pub fn get_bond_details(env: Env, bond_id: u64) -> Bond {
-        let bond_key = (bond_id, Symbol::new(&env, "bond"));
-        env.storage().persistent().get(&bond_key).unwrap()
+        let lot = Self::find_lot(&env, bond_id);
+        Bond { issue_number: bond_id, bond_status: Self::bond_status(env), ..lot.bond }
     }
 
-    pub fn get_investor_bonds(env: Env, investor: Address) -> Vec<u64> {
-        env.storage().persistent().get(&investor).unwrap_or(Vec::new(&env))
+    // Up to `limit` of an investor's ranges of consecutive ids (capped at MAX_RANGES_PAGE),
+    // starting at the `start`-th one. Ranges are appended as bonds arrive; a transfer out of
+    // the middle of a range moves its tail to the end
+    pub fn get_investor_ranges(env: Env, investor: Address, start: u32, limit: u32) -> Vec<BondRange> {
+        let count = Self::range_count(&env, &investor);
+        ranges_page(&env, count, |index| Self::range_at(&env, &investor, index), start, limit)
+    }
+
+    // Up to `limit` ids of the same holdings (capped at MAX_BOND_IDS_PAGE), starting at
+    // the `start`-th one
+    pub fn get_investor_bonds(env: Env, investor: Address, start: u32, limit: u32) -> Vec<u64> {
+        let count = Self::range_count(&env, &investor);
+        bond_ids_page(&env, count, |index| Self::range_at(&env, &investor, index), start, limit)
     }
 
     pub fn get_investor_by_bond_id(env: Env, bond_id: u64) -> Address {
         let owner_key = (bond_id, Symbol::new(&env, "owner"));
-        env.storage().persistent().get(&owner_key).unwrap()
+        env.storage().persistent().get(&owner_key)
+            .unwrap_or_else(|| Self::find_lot(&env, bond_id).investor)
     }
 
     pub fn issue_volume(env: Env) -> u64 {
         let issue_number: u64 = env.storage().persistent().get(&Symbol::new(&env, "issue_number")).unwrap_or(1);
         issue_number - 1
     }
-
-    pub fn set_bond_template(env: Env, bond_template: Bond) {
-        // Bond template
-        env.storage().persistent().set(&Symbol::new(&env, "bond_template"), &bond_template);
-    }
 }
\ No newline at end of file
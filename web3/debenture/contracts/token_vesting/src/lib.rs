@@ -0,0 +1,49 @@
+#![no_std]
+
+pub mod token_vesting;
+
+#[cfg(test)]
+pub mod test;
+
+use soroban_sdk::{contracterror, contractevent, Address};
+
+// topics ["vesting_created", beneficiary], data [schedule_id, amount]
+#[contractevent(topics = ["vesting_created"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduleCreatedEvent {
+    #[topic]
+    pub beneficiary: Address,
+    pub schedule_id: u32,
+    pub amount: i128,
+}
+
+// topics ["vesting_claimed", beneficiary], data [schedule_id, amount]
+#[contractevent(topics = ["vesting_claimed"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimedEvent {
+    #[topic]
+    pub beneficiary: Address,
+    pub schedule_id: u32,
+    pub amount: i128,
+}
+
+// topics ["vesting_revoked", beneficiary], data [schedule_id, returned amount]
+#[contractevent(topics = ["vesting_revoked"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RevokedEvent {
+    #[topic]
+    pub beneficiary: Address,
+    pub schedule_id: u32,
+    pub returned: i128,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    InvalidSchedule = 2,
+    ScheduleNotFound = 3,
+    NotRevocable = 4,
+    AlreadyRevoked = 5,
+    NothingToClaim = 6,
+}
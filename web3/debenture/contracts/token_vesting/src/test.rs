@@ -0,0 +1,114 @@
+#![cfg(test)]
+
+extern crate std;
+
+use super::token_vesting::{TokenVesting, TokenVestingClient};
+use super::Error;
+use attorneycoin::attorneycoin::{Attorneycoin, AttorneycoinClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, Env};
+
+const YEAR: u64 = 365 * 86400;
+
+fn deploy_contracts<'a>(env: &Env) -> (AttorneycoinClient<'a>, TokenVestingClient<'a>, Address) {
+    env.mock_all_auths();
+    let admin = Address::generate(env);
+
+    let token_id = env.register(Attorneycoin, (&admin, None::<i128>));
+    let token = AttorneycoinClient::new(env, &token_id);
+
+    let vesting_id = env.register(TokenVesting, (&admin, &token_id));
+    let vesting = TokenVestingClient::new(env, &vesting_id);
+
+    (token, vesting, admin)
+}
+
+#[test]
+fn test_cliff_and_linear_release() {
+    let env = Env::default();
+    let (token, vesting, admin) = deploy_contracts(&env);
+    let beneficiary = Address::generate(&env);
+    let admin_balance = token.balance(&admin);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let schedule_id = vesting.create_schedule(&beneficiary, &4_000, &1_000, &YEAR, &(4 * YEAR), &false);
+    assert_eq!(token.balance(&admin), admin_balance - 4_000);
+    assert_eq!(vesting.schedules_of(&beneficiary), vec![&env, schedule_id]);
+
+    // Before the cliff nothing is claimable
+    env.ledger().with_mut(|li| li.timestamp = 1_000 + YEAR - 1);
+    assert_eq!(vesting.vested_amount(&schedule_id), 0);
+    let result = vesting.try_claim_vested(&schedule_id);
+    assert_eq!(result, Err(Ok(Error::NothingToClaim.into())));
+
+    // At the cliff a quarter has vested linearly since start
+    env.ledger().with_mut(|li| li.timestamp = 1_000 + YEAR);
+    assert_eq!(vesting.claim_vested(&schedule_id), 1_000);
+    assert_eq!(token.balance(&beneficiary), 1_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000 + 2 * YEAR);
+    assert_eq!(vesting.vested_amount(&schedule_id), 2_000);
+    assert_eq!(vesting.unvested_amount(&schedule_id), 2_000);
+    assert_eq!(vesting.releasable_amount(&schedule_id), 1_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000 + 10 * YEAR);
+    assert_eq!(vesting.claim_vested(&schedule_id), 3_000);
+    assert_eq!(token.balance(&beneficiary), 4_000);
+    assert_eq!(vesting.unvested_amount(&schedule_id), 0);
+}
+
+#[test]
+fn test_revoke() {
+    let env = Env::default();
+    let (token, vesting, admin) = deploy_contracts(&env);
+    let beneficiary = Address::generate(&env);
+    let admin_balance = token.balance(&admin);
+
+    let revocable = vesting.create_schedule(&beneficiary, &1_000, &0, &0, &YEAR, &true);
+    let locked = vesting.create_schedule(&beneficiary, &1_000, &0, &0, &YEAR, &false);
+
+    env.ledger().with_mut(|li| li.timestamp = YEAR / 4);
+    assert_eq!(vesting.revoke(&revocable), 750);
+    assert_eq!(token.balance(&admin), admin_balance - 2_000 + 750);
+
+    let result = vesting.try_revoke(&revocable);
+    assert_eq!(result, Err(Ok(Error::AlreadyRevoked.into())));
+    let result = vesting.try_revoke(&locked);
+    assert_eq!(result, Err(Ok(Error::NotRevocable.into())));
+
+    // Vesting stops at revocation, but what had vested remains claimable
+    env.ledger().with_mut(|li| li.timestamp = YEAR);
+    assert_eq!(vesting.vested_amount(&revocable), 250);
+    assert_eq!(vesting.unvested_amount(&revocable), 0);
+    assert_eq!(vesting.claim_vested(&revocable), 250);
+}
+
+#[test]
+fn test_invalid_schedule() {
+    let env = Env::default();
+    let (_token, vesting, _admin) = deploy_contracts(&env);
+    let beneficiary = Address::generate(&env);
+
+    let result = vesting.try_create_schedule(&beneficiary, &0, &0, &0, &YEAR, &false);
+    assert_eq!(result, Err(Ok(Error::InvalidSchedule.into())));
+    let result = vesting.try_create_schedule(&beneficiary, &1_000, &0, &(2 * YEAR), &YEAR, &false);
+    assert_eq!(result, Err(Ok(Error::InvalidSchedule.into())));
+    // The end of the vesting period must fit in a timestamp
+    let result = vesting.try_create_schedule(&beneficiary, &1_000, &(u64::MAX - YEAR), &0, &(2 * YEAR), &false);
+    assert_eq!(result, Err(Ok(Error::InvalidSchedule.into())));
+    let result = vesting.try_schedule(&7);
+    assert_eq!(result, Err(Ok(Error::ScheduleNotFound.into())));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn test_claim_requires_beneficiary_auth() {
+    let env = Env::default();
+    let (_token, vesting, _admin) = deploy_contracts(&env);
+    let beneficiary = Address::generate(&env);
+    let schedule_id = vesting.create_schedule(&beneficiary, &1_000, &0, &0, &YEAR, &false);
+
+    env.ledger().with_mut(|li| li.timestamp = YEAR);
+    env.set_auths(&[]);
+    vesting.claim_vested(&schedule_id);
+}
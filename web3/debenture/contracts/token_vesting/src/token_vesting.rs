@@ -0,0 +1,216 @@
+use soroban_sdk::{contract, contractimpl, contracttype, panic_with_error, token, Address, Env, Vec};
+use crate::{Error as VestingError, ScheduleCreatedEvent, ClaimedEvent, RevokedEvent};
+
+// TTL policy (~5s ledgers), same as attorneycoin
+pub const DAY_IN_LEDGERS: u32 = 17280;
+pub const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+pub const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+pub const SCHEDULE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const SCHEDULE_LIFETIME_THRESHOLD: u32 = SCHEDULE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+// DataKey enum for storage. Admin, Token and NextScheduleId live in instance storage,
+// schedules and the per-beneficiary index in persistent entries.
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Token,
+    NextScheduleId,
+    Schedule(u32),
+    BeneficiarySchedules(Address),
+}
+
+// ATC locked for a beneficiary. Nothing vests before `start + cliff`; after that the
+// amount vests linearly from `start` until `start + duration`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct VestingSchedule {
+    pub beneficiary: Address,
+    pub amount: i128,
+    pub start: u64,
+    pub cliff: u64,
+    pub duration: u64,
+    pub revocable: bool,
+    pub claimed: i128,
+    // Timestamp at which vesting stopped, when revoked
+    pub revoked_at: Option<u64>,
+}
+
+// TokenVesting struct
+#[contract]
+pub struct TokenVesting;
+
+// -------------------------
+//  Internal helpers
+// -------------------------
+
+fn extend_instance_ttl(env: &Env) {
+    env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+fn token_client(env: &Env) -> token::Client<'_> {
+    let token: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+    token::Client::new(env, &token)
+}
+
+fn require_admin(env: &Env) -> Address {
+    let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+    admin.require_auth();
+    extend_instance_ttl(env);
+    admin
+}
+
+fn read_schedule(env: &Env, schedule_id: u32) -> VestingSchedule {
+    let key = DataKey::Schedule(schedule_id);
+    let schedule = env.storage().persistent().get(&key)
+        .unwrap_or_else(|| panic_with_error!(env, VestingError::ScheduleNotFound));
+    env.storage().persistent().extend_ttl(&key, SCHEDULE_LIFETIME_THRESHOLD, SCHEDULE_BUMP_AMOUNT);
+    schedule
+}
+
+fn write_schedule(env: &Env, schedule_id: u32, schedule: &VestingSchedule) {
+    let key = DataKey::Schedule(schedule_id);
+    env.storage().persistent().set(&key, schedule);
+    env.storage().persistent().extend_ttl(&key, SCHEDULE_LIFETIME_THRESHOLD, SCHEDULE_BUMP_AMOUNT);
+}
+
+// Amount vested at `now`, frozen at the revocation time for revoked schedules
+fn vested_at(schedule: &VestingSchedule, now: u64) -> i128 {
+    let now = schedule.revoked_at.map_or(now, |revoked_at| now.min(revoked_at));
+    if now < schedule.start + schedule.cliff {
+        0
+    } else if now >= schedule.start + schedule.duration {
+        schedule.amount
+    } else {
+        schedule.amount * (now - schedule.start) as i128 / schedule.duration as i128
+    }
+}
+
+// TokenVesting contract implementation
+#[contractimpl]
+impl TokenVesting {
+    // Sets the admin and the token being vested (ATC) at deploy time. It never runs again,
+    // so nobody can claim a fresh deployment before its owner
+    pub fn __constructor(env: Env, admin: Address, token: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Token, &token);
+        extend_instance_ttl(&env);
+    }
+
+    // Locks `amount` of the admin's tokens for `beneficiary`, returning the schedule id
+    pub fn create_schedule(
+        env: Env,
+        beneficiary: Address,
+        amount: i128,
+        start: u64,
+        cliff: u64,
+        duration: u64,
+        revocable: bool,
+    ) -> u32 {
+        let admin = require_admin(&env);
+        // vested_at adds start + cliff and start + duration; with cliff <= duration, both fit
+        // in a u64 once the latter does
+        if amount <= 0 || duration == 0 || cliff > duration || start.checked_add(duration).is_none() {
+            panic_with_error!(&env, VestingError::InvalidSchedule);
+        }
+
+        token_client(&env).transfer(&admin, env.current_contract_address(), &amount);
+
+        let schedule_id: u32 = env.storage().instance().get(&DataKey::NextScheduleId).unwrap_or(0);
+        env.storage().instance().set(&DataKey::NextScheduleId, &(schedule_id + 1));
+
+        let schedule = VestingSchedule {
+            beneficiary: beneficiary.clone(),
+            amount,
+            start,
+            cliff,
+            duration,
+            revocable,
+            claimed: 0,
+            revoked_at: None,
+        };
+        write_schedule(&env, schedule_id, &schedule);
+
+        let index_key = DataKey::BeneficiarySchedules(beneficiary.clone());
+        let mut schedules: Vec<u32> = env.storage().persistent().get(&index_key).unwrap_or(Vec::new(&env));
+        schedules.push_back(schedule_id);
+        env.storage().persistent().set(&index_key, &schedules);
+        env.storage().persistent().extend_ttl(&index_key, SCHEDULE_LIFETIME_THRESHOLD, SCHEDULE_BUMP_AMOUNT);
+
+        ScheduleCreatedEvent { beneficiary, schedule_id, amount }.publish(&env);
+        schedule_id
+    }
+
+    // Transfers everything vested and not yet claimed to the beneficiary
+    pub fn claim_vested(env: Env, schedule_id: u32) -> i128 {
+        let mut schedule = read_schedule(&env, schedule_id);
+        schedule.beneficiary.require_auth();
+
+        let releasable = vested_at(&schedule, env.ledger().timestamp()) - schedule.claimed;
+        if releasable <= 0 {
+            panic_with_error!(&env, VestingError::NothingToClaim);
+        }
+
+        schedule.claimed += releasable;
+        write_schedule(&env, schedule_id, &schedule);
+        token_client(&env).transfer(&env.current_contract_address(), &schedule.beneficiary, &releasable);
+
+        ClaimedEvent { beneficiary: schedule.beneficiary, schedule_id, amount: releasable }.publish(&env);
+        releasable
+    }
+
+    // Stops a revocable schedule: what has vested stays claimable, the rest returns to the admin
+    pub fn revoke(env: Env, schedule_id: u32) -> i128 {
+        let admin = require_admin(&env);
+        let mut schedule = read_schedule(&env, schedule_id);
+        if !schedule.revocable {
+            panic_with_error!(&env, VestingError::NotRevocable);
+        }
+        if schedule.revoked_at.is_some() {
+            panic_with_error!(&env, VestingError::AlreadyRevoked);
+        }
+
+        let now = env.ledger().timestamp();
+        let returned = schedule.amount - vested_at(&schedule, now);
+        schedule.revoked_at = Some(now);
+        write_schedule(&env, schedule_id, &schedule);
+        if returned > 0 {
+            token_client(&env).transfer(&env.current_contract_address(), &admin, &returned);
+        }
+
+        RevokedEvent { beneficiary: schedule.beneficiary, schedule_id, returned }.publish(&env);
+        returned
+    }
+
+    // ------------------------
+    //   GET Functions
+    // ------------------------
+
+    pub fn schedule(env: Env, schedule_id: u32) -> VestingSchedule {
+        read_schedule(&env, schedule_id)
+    }
+
+    pub fn schedules_of(env: Env, beneficiary: Address) -> Vec<u32> {
+        env.storage().persistent().get(&DataKey::BeneficiarySchedules(beneficiary)).unwrap_or(Vec::new(&env))
+    }
+
+    // Total vested so far, claimed or not
+    pub fn vested_amount(env: Env, schedule_id: u32) -> i128 {
+        vested_at(&read_schedule(&env, schedule_id), env.ledger().timestamp())
+    }
+
+    // Still locked; zero once a schedule is revoked, since the remainder went back to the admin
+    pub fn unvested_amount(env: Env, schedule_id: u32) -> i128 {
+        let schedule = read_schedule(&env, schedule_id);
+        if schedule.revoked_at.is_some() {
+            return 0;
+        }
+        schedule.amount - vested_at(&schedule, env.ledger().timestamp())
+    }
+
+    // Vested and not yet claimed
+    pub fn releasable_amount(env: Env, schedule_id: u32) -> i128 {
+        let schedule = read_schedule(&env, schedule_id);
+        vested_at(&schedule, env.ledger().timestamp()) - schedule.claimed
+    }
+}
@@ -38,41 +38,554 @@ export const networks = {
   }
 } as const
 
-export type DataKey = {tag: "Name", values: void} | {tag: "Symbol", values: void} | {tag: "TotalSupply", values: void} | {tag: "Balance", values: readonly [string]} | {tag: "Allowance", values: readonly [string, string]} | {tag: "Admin", values: void} | {tag: "UserLastMint", values: readonly [string]};
+export type DataKey = {tag: "Name", values: void} | {tag: "Symbol", values: void} | {tag: "TotalSupply", values: void} | {tag: "MaxSupply", values: void} | {tag: "Balance", values: readonly [string]} | {tag: "Allowance", values: readonly [string, string]} | {tag: "Admin", values: void} | {tag: "PendingAdmin", values: void} | {tag: "Minter", values: readonly [string]} | {tag: "Pauser", values: readonly [string]} | {tag: "Frozen", values: readonly [string]} | {tag: "UserLastMint", values: readonly [string]} | {tag: "FaucetConfig", values: void} | {tag: "FaucetUsage", values: void} | {tag: "FaucetAllowed", values: readonly [string]} | {tag: "Paused", values: void} | {tag: "CurrentSnapshot", values: void} | {tag: "Snapshot", values: readonly [u32]} | {tag: "BalanceCheckpoint", values: readonly [string, u32]} | {tag: "BalanceCheckpointCount", values: readonly [string]} | {tag: "SupplyCheckpoint", values: readonly [u32]} | {tag: "SupplyCheckpointCount", values: void};
 
 
-export interface TransferEvent {
+export interface Checkpoint {
+  snapshot_id: u32;
+  value: i128;
+}
+
+
+export interface FaucetUsage {
+  day: u64;
+  minted: i128;
+}
+
+
+export interface FaucetConfig {
+  allowlist_only: boolean;
   amount: i128;
-  from: string;
-  to: string;
+  cooldown: u64;
+  daily_budget: Option<i128>;
+  enabled: boolean;
 }
 
 
-export interface ApproveEvent {
-  amount: i128;
-  from: string;
-  to: string;
-}
+export interface FaucetStatus {
+  claimable: i128;
+  next_eligible_at: u64;
+}
+
+
+export interface SnapshotInfo {
+  ledger: u32;
+  timestamp: u64;
+}
+
+
+export interface AllowanceValue {
+  amount: i128;
+  expiration_ledger: u32;
+}
+
+export const Errors = {
+  1: {message:"InsufficientBalance"},
+  2: {message:"InsufficientAllowance"},
+  3: {message:"MintingCapped"},
+  4: {message:"DailyMintingReached"},
+  5: {message:"AdminOnly"},
+  6: {message:"NegativeAmount"},
+  7: {message:"InvalidExpirationLedger"},
+  9: {message:"NoPendingAdmin"},
+  10: {message:"MinterOnly"},
+  11: {message:"AccountFrozen"},
+  12: {message:"InvalidMaxSupply"},
+  13: {message:"FaucetDisabled"},
+  14: {message:"NotAllowlisted"},
+  15: {message:"FaucetBudgetExhausted"},
+  16: {message:"InvalidSnapshot"},
+  17: {message:"Paused"},
+  18: {message:"PauserOnly"},
+  19: {message:"BatchTooLarge"},
+  20: {message:"AmountOverflow"}
+}
+
+export interface Client {
+  /**
+   * Construct and simulate a burn transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  burn: ({from, amount}: {from: string, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a mint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  mint: ({minter, to, amount}: {minter: string, to: string, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a name transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  name: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  admin: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  pause: ({caller}: {caller: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a freeze transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  freeze: ({account}: {account: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a paused transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  paused: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a symbol transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  symbol: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a approve transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  approve: ({from, spender, amount, expiration_ledger}: {from: string, spender: string, amount: i128, expiration_ledger: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  balance: ({id}: {id: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a unpause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  unpause: ({caller}: {caller: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a clawback transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  clawback: ({from, amount}: {from: string, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a decimals transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  decimals: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a snapshot transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  snapshot: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  transfer: ({from, to, amount}: {from: string, to: string, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a unfreeze transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  unfreeze: ({account}: {account: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a allowance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  allowance: ({from, spender}: {from: string, spender: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a burn_from transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  burn_from: ({spender, from, amount}: {spender: string, from: string, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a is_frozen transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_frozen: ({account}: {account: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a is_minter transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_minter: ({account}: {account: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a is_pauser transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_pauser: ({account}: {account: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
 
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<boolean>>
 
-export interface MintEvent {
-  amount: i128;
-  to: string;
-}
+  /**
+   * Construct and simulate a admin_mint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  admin_mint: ({amount}: {amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
 
-export const Errors = {
-  1: {message:"InsufficientBalance"},
-  2: {message:"InsufficientAllowance"},
-  3: {message:"MintingCapped"},
-  4: {message:"DailyMintingReached"},
-  5: {message:"AdminOnly"}
-}
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a balance_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  balance_of: ({owner}: {owner: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a max_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  max_supply: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<i128>>>
 
-export interface Client {
   /**
-   * Construct and simulate a init transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a set_minter transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  init: ({admin}: {admin: string}, options?: {
+  set_minter: ({account, enabled}: {account: string, enabled: boolean}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -90,9 +603,9 @@ export interface Client {
   }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a name transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a set_pauser transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  name: (options?: {
+  set_pauser: ({account, enabled}: {account: string, enabled: boolean}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -107,12 +620,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>
+  }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a symbol transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a clawback_to transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  symbol: (options?: {
+  clawback_to: ({from, to, amount}: {from: string, to: string, amount: i128}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -127,12 +640,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>
+  }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a decimals transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a accept_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  decimals: (options?: {
+  accept_admin: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -147,7 +660,27 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u32>>
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a mint_to_user transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  mint_to_user: ({user}: {user: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
 
   /**
    * Construct and simulate a total_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -170,9 +703,9 @@ export interface Client {
   }) => Promise<AssembledTransaction<i128>>
 
   /**
-   * Construct and simulate a balance_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a balance_of_at transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  balance_of: ({owner}: {owner: string}, options?: {
+  balance_of_at: ({owner, snapshot_id}: {owner: string, snapshot_id: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -190,9 +723,9 @@ export interface Client {
   }) => Promise<AssembledTransaction<i128>>
 
   /**
-   * Construct and simulate a transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a faucet_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  transfer: ({to, amount}: {to: string, amount: i128}, options?: {
+  faucet_config: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -207,12 +740,52 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<FaucetConfig>>
 
   /**
-   * Construct and simulate a approve transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a faucet_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  faucet_status: ({user}: {user: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<FaucetStatus>>
+
+  /**
+   * Construct and simulate a pending_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  pending_admin: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a propose_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  approve: ({spender, amount}: {spender: string, amount: i128}, options?: {
+  propose_admin: ({new_admin}: {new_admin: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -230,9 +803,9 @@ export interface Client {
   }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a allowance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a snapshot_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  allowance: ({owner, spender}: {owner: string, spender: string}, options?: {
+  snapshot_info: ({snapshot_id}: {snapshot_id: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -247,12 +820,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  }) => Promise<AssembledTransaction<SnapshotInfo>>
 
   /**
    * Construct and simulate a transfer_from transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  transfer_from: ({from, to, amount}: {from: string, to: string, amount: i128}, options?: {
+  transfer_from: ({spender, from, to, amount}: {spender: string, from: string, to: string, amount: i128}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -270,9 +843,9 @@ export interface Client {
   }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a mint_to_user transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a batch_transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  mint_to_user: (options?: {
+  batch_transfer: ({from, transfers}: {from: string, transfers: Array<readonly [string, i128]>}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -290,9 +863,89 @@ export interface Client {
   }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a admin_mint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a set_max_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  admin_mint: ({amount}: {amount: i128}, options?: {
+  set_max_supply: ({max_supply}: {max_supply: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a total_supply_at transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  total_supply_at: ({snapshot_id}: {snapshot_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a set_faucet_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_faucet_config: ({config}: {config: FaucetConfig}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a remaining_mintable transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  remaining_mintable: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<i128>>>
+
+  /**
+   * Construct and simulate a set_faucet_allowed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_faucet_allowed: ({account, allowed}: {account: string, allowed: boolean}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -309,9 +962,31 @@ export interface Client {
     simulate?: boolean;
   }) => Promise<AssembledTransaction<null>>
 
+  /**
+   * Construct and simulate a current_snapshot_id transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  current_snapshot_id: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
 }
 export class Client extends ContractClient {
   static async deploy<T = Client>(
+        /** Constructor/Initialization Args for the contract's `__constructor` method */
+        {admin, max_supply}: {admin: string, max_supply: Option<i128>},
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions &
       Omit<ContractClientOptions, "contractId"> & {
@@ -323,42 +998,125 @@ export class Client extends ContractClient {
         format?: "hex" | "base64";
       }
   ): Promise<AssembledTransaction<T>> {
-    return ContractClient.deploy(null, options)
+    return ContractClient.deploy({admin, max_supply}, options)
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABwAAAAAAAAAAAAAABE5hbWUAAAAAAAAAAAAAAAZTeW1ib2wAAAAAAAAAAAAAAAAAC1RvdGFsU3VwcGx5AAAAAAEAAAAAAAAAB0JhbGFuY2UAAAAAAQAAABMAAAABAAAAAAAAAAlBbGxvd2FuY2UAAAAAAAACAAAAEwAAABMAAAAAAAAAAAAAAAVBZG1pbgAAAAAAAAEAAAAAAAAADFVzZXJMYXN0TWludAAAAAEAAAAT",
-        "AAAAAAAAAAAAAAAEaW5pdAAAAAEAAAAAAAAABWFkbWluAAAAAAAAEwAAAAA=",
-        "AAAAAAAAAAAAAAAEbmFtZQAAAAAAAAABAAAAEQ==",
-        "AAAAAAAAAAAAAAAGc3ltYm9sAAAAAAAAAAAAAQAAABE=",
+      new ContractSpec([ "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAFgAAAAAAAAAAAAAABE5hbWUAAAAAAAAAAAAAAAZTeW1ib2wAAAAAAAAAAAAAAAAAC1RvdGFsU3VwcGx5AAAAAAAAAAAAAAAACU1heFN1cHBseQAAAAAAAAEAAAAAAAAAB0JhbGFuY2UAAAAAAQAAABMAAAABAAAAAAAAAAlBbGxvd2FuY2UAAAAAAAACAAAAEwAAABMAAAAAAAAAAAAAAAVBZG1pbgAAAAAAAAAAAAAAAAAADFBlbmRpbmdBZG1pbgAAAAEAAAAAAAAABk1pbnRlcgAAAAAAAQAAABMAAAABAAAAAAAAAAZQYXVzZXIAAAAAAAEAAAATAAAAAQAAAAAAAAAGRnJvemVuAAAAAAABAAAAEwAAAAEAAAAAAAAADFVzZXJMYXN0TWludAAAAAEAAAATAAAAAAAAAAAAAAAMRmF1Y2V0Q29uZmlnAAAAAAAAAAAAAAALRmF1Y2V0VXNhZ2UAAAAAAQAAAAAAAAANRmF1Y2V0QWxsb3dlZAAAAAAAAAEAAAATAAAAAAAAAAAAAAAGUGF1c2VkAAAAAAAAAAAAAAAAAA9DdXJyZW50U25hcHNob3QAAAAAAQAAAAAAAAAIU25hcHNob3QAAAABAAAABAAAAAEAAAAAAAAAEUJhbGFuY2VDaGVja3BvaW50AAAAAAAAAgAAABMAAAAEAAAAAQAAAAAAAAAWQmFsYW5jZUNoZWNrcG9pbnRDb3VudAAAAAAAAQAAABMAAAABAAAAAAAAABBTdXBwbHlDaGVja3BvaW50AAAAAQAAAAQAAAAAAAAAAAAAABVTdXBwbHlDaGVja3BvaW50Q291bnQAAAA=",
+        "AAAAAAAAAAAAAAAEYnVybgAAAAIAAAAAAAAABGZyb20AAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
+        "AAAAAAAAAAAAAAAEbWludAAAAAMAAAAAAAAABm1pbnRlcgAAAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
+        "AAAAAAAAAAAAAAAEbmFtZQAAAAAAAAABAAAAEA==",
+        "AAAAAQAAAAAAAAAAAAAACkNoZWNrcG9pbnQAAAAAAAIAAAAAAAAAC3NuYXBzaG90X2lkAAAAAAQAAAAAAAAABXZhbHVlAAAAAAAACw==",
+        "AAAAAAAAAAAAAAAFYWRtaW4AAAAAAAAAAAAAAQAAABM=",
+        "AAAAAAAAAAAAAAAFcGF1c2UAAAAAAAABAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAA",
+        "AAAAAQAAAAAAAAAAAAAAC0ZhdWNldFVzYWdlAAAAAAIAAAAAAAAAA2RheQAAAAAGAAAAAAAAAAZtaW50ZWQAAAAAAAs=",
+        "AAAAAAAAAAAAAAAGZnJlZXplAAAAAAABAAAAAAAAAAdhY2NvdW50AAAAABMAAAAA",
+        "AAAAAAAAAAAAAAAGcGF1c2VkAAAAAAAAAAAAAQAAAAE=",
+        "AAAAAAAAAAAAAAAGc3ltYm9sAAAAAAAAAAAAAQAAABA=",
+        "AAAAAQAAAAAAAAAAAAAADEZhdWNldENvbmZpZwAAAAUAAAAAAAAADmFsbG93bGlzdF9vbmx5AAAAAAABAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAACGNvb2xkb3duAAAABgAAAAAAAAAMZGFpbHlfYnVkZ2V0AAAD6AAAAAsAAAAAAAAAB2VuYWJsZWQAAAAAAQ==",
+        "AAAAAQAAAAAAAAAAAAAADEZhdWNldFN0YXR1cwAAAAIAAAAAAAAACWNsYWltYWJsZQAAAAAAAAsAAAAAAAAAEG5leHRfZWxpZ2libGVfYXQAAAAG",
+        "AAAAAQAAAAAAAAAAAAAADFNuYXBzaG90SW5mbwAAAAIAAAAAAAAABmxlZGdlcgAAAAAABAAAAAAAAAAJdGltZXN0YW1wAAAAAAAABg==",
+        "AAAAAAAAAAAAAAAHYXBwcm92ZQAAAAAEAAAAAAAAAARmcm9tAAAAEwAAAAAAAAAHc3BlbmRlcgAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAEWV4cGlyYXRpb25fbGVkZ2VyAAAAAAAABAAAAAA=",
+        "AAAAAAAAAAAAAAAHYmFsYW5jZQAAAAABAAAAAAAAAAJpZAAAAAAAEwAAAAEAAAAL",
+        "AAAAAAAAAAAAAAAHdW5wYXVzZQAAAAABAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAA",
+        "AAAAAAAAAAAAAAAIY2xhd2JhY2sAAAACAAAAAAAAAARmcm9tAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
         "AAAAAAAAAAAAAAAIZGVjaW1hbHMAAAAAAAAAAQAAAAQ=",
-        "AAAAAAAAAAAAAAAMdG90YWxfc3VwcGx5AAAAAAAAAAEAAAAL",
-        "AAAAAAAAAAAAAAAKYmFsYW5jZV9vZgAAAAAAAQAAAAAAAAAFb3duZXIAAAAAAAATAAAAAQAAAAs=",
-        "AAAAAAAAAAAAAAAIdHJhbnNmZXIAAAACAAAAAAAAAAJ0bwAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
-        "AAAAAAAAAAAAAAAHYXBwcm92ZQAAAAACAAAAAAAAAAdzcGVuZGVyAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
-        "AAAAAAAAAAAAAAAJYWxsb3dhbmNlAAAAAAAAAgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAdzcGVuZGVyAAAAABMAAAABAAAACw==",
-        "AAAAAAAAAAAAAAANdHJhbnNmZXJfZnJvbQAAAAAAAAMAAAAAAAAABGZyb20AAAATAAAAAAAAAAJ0bwAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
-        "AAAAAAAAAAAAAAAMbWludF90b191c2VyAAAAAAAAAAA=",
+        "AAAAAAAAAAAAAAAIc25hcHNob3QAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAAAAAAAIdHJhbnNmZXIAAAADAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABQAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
+        "AAAAAAAAAAAAAAAIdW5mcmVlemUAAAABAAAAAAAAAAdhY2NvdW50AAAAABMAAAAA",
+        "AAAAAQAAAAAAAAAAAAAADkFsbG93YW5jZVZhbHVlAAAAAAACAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAEWV4cGlyYXRpb25fbGVkZ2VyAAAAAAAABA==",
+        "AAAAAAAAAAAAAAAJYWxsb3dhbmNlAAAAAAAAAgAAAAAAAAAEZnJvbQAAABMAAAAAAAAAB3NwZW5kZXIAAAAAEwAAAAEAAAAL",
+        "AAAAAAAAAAAAAAAJYnVybl9mcm9tAAAAAAAAAwAAAAAAAAAHc3BlbmRlcgAAAAATAAAAAAAAAARmcm9tAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
+        "AAAAAAAAAAAAAAAJaXNfZnJvemVuAAAAAAAAAQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAQAAAAE=",
+        "AAAAAAAAAAAAAAAJaXNfbWludGVyAAAAAAAAAQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAQAAAAE=",
+        "AAAAAAAAAAAAAAAJaXNfcGF1c2VyAAAAAAAAAQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAQAAAAE=",
         "AAAAAAAAAAAAAAAKYWRtaW5fbWludAAAAAAAAQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
-        "AAAAAQAAAAAAAAAAAAAADVRyYW5zZmVyRXZlbnQAAAAAAAADAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAABGZyb20AAAATAAAAAAAAAAJ0bwAAAAAAEw==",
-        "AAAAAQAAAAAAAAAAAAAADEFwcHJvdmVFdmVudAAAAAMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAAT",
-        "AAAAAQAAAAAAAAAAAAAACU1pbnRFdmVudAAAAAAAAAIAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAACdG8AAAAAABM=",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAABQAAAAAAAAATSW5zdWZmaWNpZW50QmFsYW5jZQAAAAABAAAAAAAAABVJbnN1ZmZpY2llbnRBbGxvd2FuY2UAAAAAAAACAAAAAAAAAA1NaW50aW5nQ2FwcGVkAAAAAAAAAwAAAAAAAAATRGFpbHlNaW50aW5nUmVhY2hlZAAAAAAEAAAAAAAAAAlBZG1pbk9ubHkAAAAAAAAF" ]),
+        "AAAAAAAAAAAAAAAKYmFsYW5jZV9vZgAAAAAAAQAAAAAAAAAFb3duZXIAAAAAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAAAAAAAKbWF4X3N1cHBseQAAAAAAAAAAAAEAAAPoAAAACw==",
+        "AAAAAAAAAAAAAAAKc2V0X21pbnRlcgAAAAAAAgAAAAAAAAAHYWNjb3VudAAAAAATAAAAAAAAAAdlbmFibGVkAAAAAAEAAAAA",
+        "AAAAAAAAAAAAAAAKc2V0X3BhdXNlcgAAAAAAAgAAAAAAAAAHYWNjb3VudAAAAAATAAAAAAAAAAdlbmFibGVkAAAAAAEAAAAA",
+        "AAAAAAAAAAAAAAALY2xhd2JhY2tfdG8AAAAAAwAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
+        "AAAAAAAAAAAAAAAMYWNjZXB0X2FkbWluAAAAAAAAAAA=",
+        "AAAAAAAAAAAAAAAMbWludF90b191c2VyAAAAAQAAAAAAAAAEdXNlcgAAABMAAAAA",
+        "AAAAAAAAAAAAAAAMdG90YWxfc3VwcGx5AAAAAAAAAAEAAAAL",
+        "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAKbWF4X3N1cHBseQAAAAAD6AAAAAsAAAAA",
+        "AAAAAAAAAAAAAAANYmFsYW5jZV9vZl9hdAAAAAAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAALc25hcHNob3RfaWQAAAAABAAAAAEAAAAL",
+        "AAAAAAAAAAAAAAANZmF1Y2V0X2NvbmZpZwAAAAAAAAAAAAABAAAH0AAAAAxGYXVjZXRDb25maWc=",
+        "AAAAAAAAAAAAAAANZmF1Y2V0X3N0YXR1cwAAAAAAAAEAAAAAAAAABHVzZXIAAAATAAAAAQAAB9AAAAAMRmF1Y2V0U3RhdHVz",
+        "AAAAAAAAAAAAAAANcGVuZGluZ19hZG1pbgAAAAAAAAAAAAABAAAD6AAAABM=",
+        "AAAAAAAAAAAAAAANcHJvcG9zZV9hZG1pbgAAAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
+        "AAAAAAAAAAAAAAANc25hcHNob3RfaW5mbwAAAAAAAAEAAAAAAAAAC3NuYXBzaG90X2lkAAAAAAQAAAABAAAH0AAAAAxTbmFwc2hvdEluZm8=",
+        "AAAAAAAAAAAAAAANdHJhbnNmZXJfZnJvbQAAAAAAAAQAAAAAAAAAB3NwZW5kZXIAAAAAEwAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
+        "AAAAAAAAAAAAAAAOYmF0Y2hfdHJhbnNmZXIAAAAAAAIAAAAAAAAABGZyb20AAAATAAAAAAAAAAl0cmFuc2ZlcnMAAAAAAAPqAAAD7QAAAAIAAAATAAAACwAAAAA=",
+        "AAAAAAAAAAAAAAAOc2V0X21heF9zdXBwbHkAAAAAAAEAAAAAAAAACm1heF9zdXBwbHkAAAAAAAsAAAAA",
+        "AAAAAAAAAAAAAAAPdG90YWxfc3VwcGx5X2F0AAAAAAEAAAAAAAAAC3NuYXBzaG90X2lkAAAAAAQAAAABAAAACw==",
+        "AAAAAAAAAAAAAAARc2V0X2ZhdWNldF9jb25maWcAAAAAAAABAAAAAAAAAAZjb25maWcAAAAAB9AAAAAMRmF1Y2V0Q29uZmlnAAAAAA==",
+        "AAAAAAAAAAAAAAAScmVtYWluaW5nX21pbnRhYmxlAAAAAAAAAAAAAQAAA+gAAAAL",
+        "AAAAAAAAAAAAAAASc2V0X2ZhdWNldF9hbGxvd2VkAAAAAAACAAAAAAAAAAdhY2NvdW50AAAAABMAAAAAAAAAB2FsbG93ZWQAAAAAAQAAAAA=",
+        "AAAAAAAAAAAAAAATY3VycmVudF9zbmFwc2hvdF9pZAAAAAAAAAAAAQAAAAQ=",
+        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAEwAAAAAAAAATSW5zdWZmaWNpZW50QmFsYW5jZQAAAAABAAAAAAAAABVJbnN1ZmZpY2llbnRBbGxvd2FuY2UAAAAAAAACAAAAAAAAAA1NaW50aW5nQ2FwcGVkAAAAAAAAAwAAAAAAAAATRGFpbHlNaW50aW5nUmVhY2hlZAAAAAAEAAAAAAAAAAlBZG1pbk9ubHkAAAAAAAAFAAAAAAAAAA5OZWdhdGl2ZUFtb3VudAAAAAAABgAAAAAAAAAXSW52YWxpZEV4cGlyYXRpb25MZWRnZXIAAAAABwAAAAAAAAAOTm9QZW5kaW5nQWRtaW4AAAAAAAkAAAAAAAAACk1pbnRlck9ubHkAAAAAAAoAAAAAAAAADUFjY291bnRGcm96ZW4AAAAAAAALAAAAAAAAABBJbnZhbGlkTWF4U3VwcGx5AAAADAAAAAAAAAAORmF1Y2V0RGlzYWJsZWQAAAAAAA0AAAAAAAAADk5vdEFsbG93bGlzdGVkAAAAAAAOAAAAAAAAABVGYXVjZXRCdWRnZXRFeGhhdXN0ZWQAAAAAAAAPAAAAAAAAAA9JbnZhbGlkU25hcHNob3QAAAAAEAAAAAAAAAAGUGF1c2VkAAAAAAARAAAAAAAAAApQYXVzZXJPbmx5AAAAAAASAAAAAAAAAA1CYXRjaFRvb0xhcmdlAAAAAAAAEwAAAAAAAAAOQW1vdW50T3ZlcmZsb3cAAAAAABQ=",
+        "AAAABQAAAAAAAAAAAAAACUJ1cm5FdmVudAAAAAAAAAEAAAAEYnVybgAAAAIAAAAAAAAABGZyb20AAAATAAAAAQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAA=",
+        "AAAABQAAAAAAAAAAAAAACU1pbnRFdmVudAAAAAAAAAEAAAAEbWludAAAAAIAAAAAAAAAAnRvAAAAAAATAAAAAQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAA=",
+        "AAAABQAAAAAAAAAAAAAACVJvbGVFdmVudAAAAAAAAAEAAAAEcm9sZQAAAAMAAAAAAAAABHJvbGUAAAARAAAAAQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAQAAAAAAAAAHZW5hYmxlZAAAAAABAAAAAAAAAAA=",
+        "AAAABQAAAAAAAAAAAAAAClBhdXNlRXZlbnQAAAAAAAEAAAAFcGF1c2UAAAAAAAABAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAAAA==",
+        "AAAABQAAAAAAAAAAAAAAC0ZhdWNldEV2ZW50AAAAAAEAAAAGZmF1Y2V0AAAAAAACAAAAAAAAAAR1c2VyAAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAA",
+        "AAAABQAAAAAAAAAAAAAAC0ZyZWV6ZUV2ZW50AAAAAAEAAAAGZnJlZXplAAAAAAABAAAAAAAAAAdhY2NvdW50AAAAABMAAAABAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAADEFwcHJvdmVFdmVudAAAAAEAAAAHYXBwcm92ZQAAAAAEAAAAAAAAAARmcm9tAAAAEwAAAAEAAAAAAAAAB3NwZW5kZXIAAAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAAAAAAEWV4cGlyYXRpb25fbGVkZ2VyAAAAAAAABAAAAAAAAAAB",
+        "AAAABQAAAAAAAAAAAAAADFVucGF1c2VFdmVudAAAAAEAAAAHdW5wYXVzZQAAAAABAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAAAA==",
+        "AAAABQAAAAAAAAAAAAAADUNsYXdiYWNrRXZlbnQAAAAAAAABAAAACGNsYXdiYWNrAAAAAwAAAAAAAAAEZnJvbQAAABMAAAABAAAAAAAAAAJ0bwAAAAAD6AAAABMAAAAAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAADVNuYXBzaG90RXZlbnQAAAAAAAABAAAACHNuYXBzaG90AAAAAwAAAAAAAAALc25hcHNob3RfaWQAAAAABAAAAAEAAAAAAAAABmxlZGdlcgAAAAAABAAAAAAAAAAAAAAACXRpbWVzdGFtcAAAAAAAAAYAAAAAAAAAAQ==",
+        "AAAABQAAAAAAAAAAAAAADVRyYW5zZmVyRXZlbnQAAAAAAAABAAAACHRyYW5zZmVyAAAABAAAAAAAAAAEZnJvbQAAABMAAAABAAAAAAAAAAJ0bwAAAAAAEwAAAAEAAAAAAAAAC3RvX211eGVkX2lkAAAAA+gAAAAGAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAADVVuZnJlZXplRXZlbnQAAAAAAAABAAAACHVuZnJlZXplAAAAAQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAQAAAAI=",
+        "AAAABQAAAAAAAAAAAAAADk1heFN1cHBseUV2ZW50AAAAAAABAAAACm1heF9zdXBwbHkAAAAAAAEAAAAAAAAACm1heF9zdXBwbHkAAAAAAAsAAAAAAAAAAA==",
+        "AAAABQAAAAAAAAAAAAAAEUFkbWluQ2hhbmdlZEV2ZW50AAAAAAAAAQAAAAlzZXRfYWRtaW4AAAAAAAACAAAAAAAAAA5wcmV2aW91c19hZG1pbgAAAAAAEwAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAAAAAAAA==",
+        "AAAABQAAAAAAAAAAAAAAEkFkbWluUHJvcG9zZWRFdmVudAAAAAAAAQAAAA5hZG1pbl9wcm9wb3NlZAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAAAAAAA" ]),
       options
     )
   }
   public readonly fromJSON = {
-    init: this.txFromJSON<null>,
+    burn: this.txFromJSON<null>,
+        mint: this.txFromJSON<null>,
         name: this.txFromJSON<string>,
+        admin: this.txFromJSON<string>,
+        pause: this.txFromJSON<null>,
+        freeze: this.txFromJSON<null>,
+        paused: this.txFromJSON<boolean>,
         symbol: this.txFromJSON<string>,
+        approve: this.txFromJSON<null>,
+        balance: this.txFromJSON<i128>,
+        unpause: this.txFromJSON<null>,
+        clawback: this.txFromJSON<null>,
         decimals: this.txFromJSON<u32>,
-        total_supply: this.txFromJSON<i128>,
-        balance_of: this.txFromJSON<i128>,
+        snapshot: this.txFromJSON<u32>,
         transfer: this.txFromJSON<null>,
-        approve: this.txFromJSON<null>,
+        unfreeze: this.txFromJSON<null>,
         allowance: this.txFromJSON<i128>,
-        transfer_from: this.txFromJSON<null>,
+        burn_from: this.txFromJSON<null>,
+        is_frozen: this.txFromJSON<boolean>,
+        is_minter: this.txFromJSON<boolean>,
+        is_pauser: this.txFromJSON<boolean>,
+        admin_mint: this.txFromJSON<null>,
+        balance_of: this.txFromJSON<i128>,
+        max_supply: this.txFromJSON<Option<i128>>,
+        set_minter: this.txFromJSON<null>,
+        set_pauser: this.txFromJSON<null>,
+        clawback_to: this.txFromJSON<null>,
+        accept_admin: this.txFromJSON<null>,
         mint_to_user: this.txFromJSON<null>,
-        admin_mint: this.txFromJSON<null>
+        total_supply: this.txFromJSON<i128>,
+        balance_of_at: this.txFromJSON<i128>,
+        faucet_config: this.txFromJSON<FaucetConfig>,
+        faucet_status: this.txFromJSON<FaucetStatus>,
+        pending_admin: this.txFromJSON<Option<string>>,
+        propose_admin: this.txFromJSON<null>,
+        snapshot_info: this.txFromJSON<SnapshotInfo>,
+        transfer_from: this.txFromJSON<null>,
+        batch_transfer: this.txFromJSON<null>,
+        set_max_supply: this.txFromJSON<null>,
+        total_supply_at: this.txFromJSON<i128>,
+        set_faucet_config: this.txFromJSON<null>,
+        remaining_mintable: this.txFromJSON<Option<i128>>,
+        set_faucet_allowed: this.txFromJSON<null>,
+        current_snapshot_id: this.txFromJSON<u32>
   }
 }
\ No newline at end of file
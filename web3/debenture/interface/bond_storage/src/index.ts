@@ -39,37 +39,786 @@ export const networks = {
 } as const
 
 
+export interface Breach {
+  covenant_id: u32;
+  observed: i128;
+  reported_at: u64;
+  status: BreachStatus;
+}
+
+
+export interface Issuer {
+  cnpj: string;
+  institution: string;
+  lei: string;
+}
+
+
+export interface Rating {
+  agency: string;
+  date: u64;
+  grade: string;
+  notch: u32;
+  outlook: Outlook;
+  report_hash: Buffer;
+  scale: RatingScale;
+}
+
+export type DataKey = {tag: "Manager", values: void} | {tag: "SeriesCount", values: void} | {tag: "SeriesAt", values: readonly [u32]} | {tag: "SeriesManager", values: readonly [string]} | {tag: "Bond", values: readonly [string]} | {tag: "Issuer", values: readonly [string]} | {tag: "Principal", values: readonly [string]} | {tag: "DocumentCount", values: readonly [string]} | {tag: "Document", values: readonly [string, u32]} | {tag: "DocumentHash", values: readonly [string, Buffer]} | {tag: "Indenture", values: readonly [string]} | {tag: "Amortization", values: readonly [string]} | {tag: "Trustee", values: readonly [string]} | {tag: "Covenants", values: readonly [string]} | {tag: "BreachCount", values: readonly [string]} | {tag: "Breach", values: readonly [string, u32]} | {tag: "Guarantee", values: readonly [string]} | {tag: "RatingAgency", values: readonly [string]} | {tag: "RatingCount", values: readonly [string]} | {tag: "Rating", values: readonly [string, u32]} | {tag: "LatestRating", values: readonly [string, string, RatingScale]} | {tag: "VersionCount", values: readonly [string]} | {tag: "Version", values: readonly [string, u32]};
+
+export type Outlook = {tag: "Positive", values: void} | {tag: "Stable", values: void} | {tag: "Negative", values: void} | {tag: "Developing", values: void};
+
+
+export interface Covenant {
+  automatic: boolean;
+  bound: CovenantBound;
+  description_hash: Buffer;
+  metric: string;
+  threshold: i128;
+}
+
+
+export interface Document {
+  anchored_at: u64;
+  doc_type: DocumentType;
+  effective_date: u64;
+  hash: Buffer;
+  uri: string;
+}
+
+
+export interface Guarantee {
+  collateral: Array<Collateral>;
+  coverage_ratio: i128;
+  guarantee_type: GuaranteeType;
+  guarantors: Array<string>;
+  updated_at: u64;
+}
+
+
+export interface Principal {
+  paid: i128;
+  placed: i128;
+}
+
+
+export interface Collateral {
+  amount: i128;
+  asset: CollateralRef;
+  value: i128;
+}
+
+export type RatingScale = {tag: "National", values: void} | {tag: "Global", values: void};
+
+
+export interface Amortization {
+  date: u64;
+  percentage: i128;
+}
+
+export type BreachStatus = {tag: "Pending", values: void} | {tag: "Waived", values: void} | {tag: "Accelerated", values: void};
+
+export type DocumentType = {tag: "Indenture", values: void} | {tag: "Amendment", values: void} | {tag: "Other", values: void};
+
+export type RatingAction = {tag: "Upgrade", values: void} | {tag: "Downgrade", values: void};
+
+
+export interface TermsVersion {
+  author: string;
+  bond: Bond;
+  issuer: Issuer;
+  ledger: u32;
+  timestamp: u64;
+  version: u32;
+}
+
+export type CollateralRef = {tag: "OnChain", values: readonly [string]} | {tag: "OffChain", values: readonly [Buffer]};
+
+export type CovenantBound = {tag: "Max", values: void} | {tag: "Min", values: void};
+
+export type GuaranteeType = {tag: "Quirografaria", values: void} | {tag: "Real", values: void} | {tag: "Flutuante", values: void} | {tag: "Subordinada", values: void};
+
+export type AmortizationKind = {tag: "Bullet", values: void} | {tag: "Linear", values: void} | {tag: "Custom", values: void};
+
+export type AmortizationPlan = {tag: "Bullet", values: void} | {tag: "Linear", values: readonly [Array<u64>]} | {tag: "Custom", values: readonly [Array<Amortization>]};
+
+
+export interface AmortizationSchedule {
+  kind: AmortizationKind;
+  payments: Array<Amortization>;
+}
+
+export const Errors = {
+  2: {message:"SeriesAlreadyRegistered"},
+  3: {message:"SeriesNotFound"},
+  4: {message:"InvalidIsin"},
+  5: {message:"InvalidCnpj"},
+  6: {message:"InvalidLei"},
+  7: {message:"InvalidStatusTransition"},
+  8: {message:"NotYetMatured"},
+  9: {message:"PrincipalOutstanding"},
+  10: {message:"InvalidAmount"},
+  11: {message:"IndentureAlreadyAnchored"},
+  12: {message:"MissingIndenture"},
+  13: {message:"DuplicateDocument"},
+  14: {message:"VersionNotFound"},
+  15: {message:"InvalidRemuneration"},
+  16: {message:"InvalidSchedule"},
+  17: {message:"ScheduleNotFound"},
+  18: {message:"TrusteeNotSet"},
+  19: {message:"CovenantNotFound"},
+  20: {message:"NotABreach"},
+  21: {message:"BreachNotFound"},
+  22: {message:"BreachNotPending"},
+  23: {message:"NotRatingAgency"},
+  24: {message:"InvalidRating"},
+  25: {message:"InvalidGuarantee"},
+  26: {message:"TooManyCovenants"}
+}
+
+
 export interface Bond {
   bond_status: BondStatus;
   currency: string;
   denomination: i128;
   frequency: u64;
-  interest_rate: i128;
   issue_date: u64;
   issue_number: u64;
   maturity_date: u64;
+  remuneration: Remuneration;
+}
+
+export type Indexer = {tag: "Prefixado", values: void} | {tag: "Cdi", values: void} | {tag: "Ipca", values: void};
+
+export type DayCount = {tag: "Du252", values: void} | {tag: "Thirty360", values: void} | {tag: "Act365", values: void};
+
+
+export interface BondRange {
+  first_id: u64;
+  quantity: u64;
+}
+
+
+export interface IssueData {
+  investor: string;
+  principal: u64;
+}
+
+export type BondStatus = {tag: "Registered", values: void} | {tag: "Offered", values: void} | {tag: "Issued", values: void} | {tag: "Matured", values: void} | {tag: "Redeemed", values: void};
+
+
+export interface Remuneration {
+  day_count: DayCount;
+  index_percentage: i128;
+  indexer: Indexer;
+  spread: i128;
 }
 
+export interface Client {
+  /**
+   * Construct and simulate a lei transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  lei: ({isin}: {isin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a cnpj transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  cnpj: ({isin}: {isin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a breach transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  breach: ({isin, breach_id}: {isin: string, breach_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Breach>>
+
+  /**
+   * Construct and simulate a trustee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  trustee: ({isin}: {isin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a breaches transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  breaches: ({isin, start, limit}: {isin: string, start: u32, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<Breach>>>
+
+  /**
+   * Construct and simulate a currency transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  currency: ({isin}: {isin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a schedule transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  schedule: ({isin}: {isin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<AmortizationSchedule>>
+
+  /**
+   * Construct and simulate a set_bond transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_bond: ({isin, bond}: {isin: string, bond: Bond}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a bond_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  bond_info: ({isin}: {isin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Bond>>
+
+  /**
+   * Construct and simulate a covenants transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  covenants: ({isin}: {isin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<Covenant>>>
+
+  /**
+   * Construct and simulate a documents transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  documents: ({isin, start, limit}: {isin: string, start: u32, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<Document>>>
+
+  /**
+   * Construct and simulate a frequency transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  frequency: ({isin}: {isin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a guarantee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  guarantee: ({isin}: {isin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<Guarantee>>>
+
+  /**
+   * Construct and simulate a principal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  principal: ({isin}: {isin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Principal>>
+
+  /**
+   * Construct and simulate a issue_date transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  issue_date: ({isin}: {isin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a set_issuer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_issuer: ({isin, issuer}: {isin: string, issuer: Issuer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a bond_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  bond_status: ({isin}: {isin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<BondStatus>>
+
+  /**
+   * Construct and simulate a institution transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  institution: ({isin}: {isin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a issuer_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  issuer_info: ({isin}: {isin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Issuer>>
+
+  /**
+   * Construct and simulate a list_series transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  list_series: ({start, limit}: {start: u32, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<string>>>
+
+  /**
+   * Construct and simulate a set_trustee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_trustee: ({isin, trustee}: {isin: string, trustee: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a add_covenant transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  add_covenant: ({isin, covenant}: {isin: string, covenant: Covenant}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a bond_history transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  bond_history: ({isin, start, limit}: {isin: string, start: u32, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<TermsVersion>>>
+
+  /**
+   * Construct and simulate a bond_info_at transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  bond_info_at: ({isin, version}: {isin: string, version: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<TermsVersion>>
+
+  /**
+   * Construct and simulate a breach_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  breach_count: ({isin}: {isin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a denomination transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  denomination: ({isin}: {isin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a mark_matured transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  mark_matured: ({isin}: {isin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
 
-export interface Issuer {
-  cnpj: string;
-  institution: string;
-  lei: string;
-}
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
 
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
 
-export interface IssueData {
-  investor: string;
-  principal: u64;
-}
+  /**
+   * Construct and simulate a rating_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  rating_count: ({isin}: {isin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
 
-export type BondStatus = {tag: "Offered", values: void} | {tag: "Issued", values: void} | {tag: "Matured", values: void} | {tag: "Redeemed", values: void};
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a remuneration transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  remuneration: ({isin}: {isin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Remuneration>>
+
+  /**
+   * Construct and simulate a series_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  series_count: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
 
-export interface Client {
   /**
-   * Construct and simulate a initialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a waive_breach transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  initialize: ({manager}: {manager: string}, options?: {
+  waive_breach: ({isin, breach_id}: {isin: string, breach_id: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -87,9 +836,9 @@ export interface Client {
   }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a set_bond transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a mark_redeemed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  set_bond: ({bond}: {bond: Bond}, options?: {
+  mark_redeemed: ({isin}: {isin: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -107,9 +856,29 @@ export interface Client {
   }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a set_issuer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a maturity_date transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  maturity_date: ({isin}: {isin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a open_offering transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  set_issuer: ({issuer}: {issuer: Issuer}, options?: {
+  open_offering: ({isin}: {isin: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -127,9 +896,9 @@ export interface Client {
   }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a institution transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a report_breach transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  institution: (options?: {
+  report_breach: ({isin, covenant_id, observed}: {isin: string, covenant_id: u32, observed: i128}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -144,12 +913,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>
+  }) => Promise<AssembledTransaction<u32>>
 
   /**
-   * Construct and simulate a cnpj transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a set_guarantee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  cnpj: (options?: {
+  set_guarantee: ({isin, guarantee_type, collateral, coverage_ratio, guarantors}: {isin: string, guarantee_type: GuaranteeType, collateral: Array<Collateral>, coverage_ratio: i128, guarantors: Array<string>}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -164,12 +933,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>
+  }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a lei transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a version_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  lei: (options?: {
+  version_count: ({isin}: {isin: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -184,12 +953,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>
+  }) => Promise<AssembledTransaction<u32>>
 
   /**
-   * Construct and simulate a bond_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a close_offering transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  bond_info: (options?: {
+  close_offering: ({isin, placed_principal}: {isin: string, placed_principal: i128}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -204,12 +973,92 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Bond>>
+  }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a currency transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a current_rating transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  current_rating: ({isin}: {isin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<Rating>>>
+
+  /**
+   * Construct and simulate a document_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  document_count: ({isin}: {isin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a publish_rating transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  publish_rating: ({isin, rating}: {isin: string, rating: Rating}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a rating_history transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  rating_history: ({isin, start, limit}: {isin: string, start: u32, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<Rating>>>
+
+  /**
+   * Construct and simulate a series_manager transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  currency: (options?: {
+  series_manager: ({isin}: {isin: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -227,9 +1076,9 @@ export interface Client {
   }) => Promise<AssembledTransaction<string>>
 
   /**
-   * Construct and simulate a denomination transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a anchor_document transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  denomination: (options?: {
+  anchor_document: ({isin, doc_type, hash, uri, effective_date}: {isin: string, doc_type: DocumentType, hash: Buffer, uri: string, effective_date: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -244,12 +1093,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  }) => Promise<AssembledTransaction<u32>>
 
   /**
-   * Construct and simulate a frequency transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a register_series transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  frequency: (options?: {
+  register_series: ({isin, bond, issuer}: {isin: string, bond: Bond, issuer: Issuer}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -264,12 +1113,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+  }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a interest_rate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a is_rating_agency transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  interest_rate: (options?: {
+  is_rating_agency: ({agency}: {agency: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -284,12 +1133,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  }) => Promise<AssembledTransaction<boolean>>
 
   /**
-   * Construct and simulate a issue_date transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a set_amortization transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  issue_date: (options?: {
+  set_amortization: ({isin, plan}: {isin: string, plan: AmortizationPlan}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -304,12 +1153,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+  }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a maturity_date transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a next_payment_date transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  maturity_date: (options?: {
+  next_payment_date: ({isin}: {isin: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -324,12 +1173,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+  }) => Promise<AssembledTransaction<Option<u64>>>
 
   /**
-   * Construct and simulate a issuer_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a set_rating_agency transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  issuer_info: (options?: {
+  set_rating_agency: ({agency, enabled}: {agency: string, enabled: boolean}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -344,11 +1193,73 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Issuer>>
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_series_manager transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_series_manager: ({isin, manager}: {isin: string, manager: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a declare_early_maturity transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  declare_early_maturity: ({isin, breach_id}: {isin: string, breach_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a record_principal_payment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  record_principal_payment: ({isin, amount}: {isin: string, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
 
 }
 export class Client extends ContractClient {
   static async deploy<T = Client>(
+        /** Constructor/Initialization Args for the contract's `__constructor` method */
+        {manager}: {manager: string},
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions &
       Omit<ContractClientOptions, "contractId"> & {
@@ -360,45 +1271,155 @@ export class Client extends ContractClient {
         format?: "hex" | "base64";
       }
   ): Promise<AssembledTransaction<T>> {
-    return ContractClient.deploy(null, options)
+    return ContractClient.deploy({manager}, options)
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEJvbmQAAAAIAAAAAAAAAAtib25kX3N0YXR1cwAAAAfQAAAACkJvbmRTdGF0dXMAAAAAAAAAAAAIY3VycmVuY3kAAAATAAAAAAAAAAxkZW5vbWluYXRpb24AAAALAAAAAAAAAAlmcmVxdWVuY3kAAAAAAAAGAAAAAAAAAA1pbnRlcmVzdF9yYXRlAAAAAAAACwAAAAAAAAAKaXNzdWVfZGF0ZQAAAAAABgAAAAAAAAAMaXNzdWVfbnVtYmVyAAAABgAAAAAAAAANbWF0dXJpdHlfZGF0ZQAAAAAAAAY=",
+      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABkJyZWFjaAAAAAAABAAAAAAAAAALY292ZW5hbnRfaWQAAAAABAAAAAAAAAAIb2JzZXJ2ZWQAAAALAAAAAAAAAAtyZXBvcnRlZF9hdAAAAAAGAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAAMQnJlYWNoU3RhdHVz",
         "AAAAAQAAAAAAAAAAAAAABklzc3VlcgAAAAAAAwAAAAAAAAAEY25wagAAABAAAAAAAAAAC2luc3RpdHV0aW9uAAAAABMAAAAAAAAAA2xlaQAAAAAQ",
+        "AAAAAQAAAAAAAAAAAAAABlJhdGluZwAAAAAABwAAAAAAAAAGYWdlbmN5AAAAAAATAAAAAAAAAARkYXRlAAAABgAAAAAAAAAFZ3JhZGUAAAAAAAAQAAAAAAAAAAVub3RjaAAAAAAAAAQAAAAAAAAAB291dGxvb2sAAAAH0AAAAAdPdXRsb29rAAAAAAAAAAALcmVwb3J0X2hhc2gAAAAD7gAAACAAAAAAAAAABXNjYWxlAAAAAAAH0AAAAAtSYXRpbmdTY2FsZQA=",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAFwAAAAAAAAAAAAAAB01hbmFnZXIAAAAAAAAAAAAAAAALU2VyaWVzQ291bnQAAAAAAQAAAAAAAAAIU2VyaWVzQXQAAAABAAAABAAAAAEAAAAAAAAADVNlcmllc01hbmFnZXIAAAAAAAABAAAAEAAAAAEAAAAAAAAABEJvbmQAAAABAAAAEAAAAAEAAAAAAAAABklzc3VlcgAAAAAAAQAAABAAAAABAAAAAAAAAAlQcmluY2lwYWwAAAAAAAABAAAAEAAAAAEAAAAAAAAADURvY3VtZW50Q291bnQAAAAAAAABAAAAEAAAAAEAAAAAAAAACERvY3VtZW50AAAAAgAAABAAAAAEAAAAAQAAAAAAAAAMRG9jdW1lbnRIYXNoAAAAAgAAABAAAAPuAAAAIAAAAAEAAAAAAAAACUluZGVudHVyZQAAAAAAAAEAAAAQAAAAAQAAAAAAAAAMQW1vcnRpemF0aW9uAAAAAQAAABAAAAABAAAAAAAAAAdUcnVzdGVlAAAAAAEAAAAQAAAAAQAAAAAAAAAJQ292ZW5hbnRzAAAAAAAAAQAAABAAAAABAAAAAAAAAAtCcmVhY2hDb3VudAAAAAABAAAAEAAAAAEAAAAAAAAABkJyZWFjaAAAAAAAAgAAABAAAAAEAAAAAQAAAAAAAAAJR3VhcmFudGVlAAAAAAAAAQAAABAAAAABAAAAAAAAAAxSYXRpbmdBZ2VuY3kAAAABAAAAEwAAAAEAAAAAAAAAC1JhdGluZ0NvdW50AAAAAAEAAAAQAAAAAQAAAAAAAAAGUmF0aW5nAAAAAAACAAAAEAAAAAQAAAABAAAAAAAAAAxMYXRlc3RSYXRpbmcAAAADAAAAEAAAABMAAAfQAAAAC1JhdGluZ1NjYWxlAAAAAAEAAAAAAAAADFZlcnNpb25Db3VudAAAAAEAAAAQAAAAAQAAAAAAAAAHVmVyc2lvbgAAAAACAAAAEAAAAAQ=",
+        "AAAAAgAAAAAAAAAAAAAAB091dGxvb2sAAAAABAAAAAAAAAAAAAAACFBvc2l0aXZlAAAAAAAAAAAAAAAGU3RhYmxlAAAAAAAAAAAAAAAAAAhOZWdhdGl2ZQAAAAAAAAAAAAAACkRldmVsb3BpbmcAAA==",
+        "AAAAAAAAAAAAAAADbGVpAAAAAAEAAAAAAAAABGlzaW4AAAAQAAAAAQAAABA=",
+        "AAAAAQAAAAAAAAAAAAAACENvdmVuYW50AAAABQAAAAAAAAAJYXV0b21hdGljAAAAAAAAAQAAAAAAAAAFYm91bmQAAAAAAAfQAAAADUNvdmVuYW50Qm91bmQAAAAAAAAAAAAAEGRlc2NyaXB0aW9uX2hhc2gAAAPuAAAAIAAAAAAAAAAGbWV0cmljAAAAAAARAAAAAAAAAAl0aHJlc2hvbGQAAAAAAAAL",
+        "AAAAAQAAAAAAAAAAAAAACERvY3VtZW50AAAABQAAAAAAAAALYW5jaG9yZWRfYXQAAAAABgAAAAAAAAAIZG9jX3R5cGUAAAfQAAAADERvY3VtZW50VHlwZQAAAAAAAAAOZWZmZWN0aXZlX2RhdGUAAAAAAAYAAAAAAAAABGhhc2gAAAPuAAAAIAAAAAAAAAADdXJpAAAAABA=",
+        "AAAAAAAAAAAAAAAEY25wagAAAAEAAAAAAAAABGlzaW4AAAAQAAAAAQAAABA=",
+        "AAAAAQAAAAAAAAAAAAAACUd1YXJhbnRlZQAAAAAAAAUAAAAAAAAACmNvbGxhdGVyYWwAAAAAA+oAAAfQAAAACkNvbGxhdGVyYWwAAAAAAAAAAAAOY292ZXJhZ2VfcmF0aW8AAAAAAAsAAAAAAAAADmd1YXJhbnRlZV90eXBlAAAAAAfQAAAADUd1YXJhbnRlZVR5cGUAAAAAAAAAAAAACmd1YXJhbnRvcnMAAAAAA+oAAAATAAAAAAAAAAp1cGRhdGVkX2F0AAAAAAAG",
+        "AAAAAQAAAAAAAAAAAAAACVByaW5jaXBhbAAAAAAAAAIAAAAAAAAABHBhaWQAAAALAAAAAAAAAAZwbGFjZWQAAAAAAAs=",
+        "AAAAAQAAAAAAAAAAAAAACkNvbGxhdGVyYWwAAAAAAAMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAFYXNzZXQAAAAAAAfQAAAADUNvbGxhdGVyYWxSZWYAAAAAAAAAAAAABXZhbHVlAAAAAAAACw==",
+        "AAAAAAAAAAAAAAAGYnJlYWNoAAAAAAACAAAAAAAAAARpc2luAAAAEAAAAAAAAAAJYnJlYWNoX2lkAAAAAAAABAAAAAEAAAfQAAAABkJyZWFjaAAA",
+        "AAAAAgAAAAAAAAAAAAAAC1JhdGluZ1NjYWxlAAAAAAIAAAAAAAAAAAAAAAhOYXRpb25hbAAAAAAAAAAAAAAABkdsb2JhbAAA",
+        "AAAAAAAAAAAAAAAHdHJ1c3RlZQAAAAABAAAAAAAAAARpc2luAAAAEAAAAAEAAAPoAAAAEw==",
+        "AAAAAQAAAAAAAAAAAAAADEFtb3J0aXphdGlvbgAAAAIAAAAAAAAABGRhdGUAAAAGAAAAAAAAAApwZXJjZW50YWdlAAAAAAAL",
+        "AAAAAgAAAAAAAAAAAAAADEJyZWFjaFN0YXR1cwAAAAMAAAAAAAAAAAAAAAdQZW5kaW5nAAAAAAAAAAAAAAAABldhaXZlZAAAAAAAAAAAAAAAAAALQWNjZWxlcmF0ZWQA",
+        "AAAAAgAAAAAAAAAAAAAADERvY3VtZW50VHlwZQAAAAMAAAAAAAAAAAAAAAlJbmRlbnR1cmUAAAAAAAAAAAAAAAAAAAlBbWVuZG1lbnQAAAAAAAAAAAAAAAAAAAVPdGhlcgAAAA==",
+        "AAAAAgAAAAAAAAAAAAAADFJhdGluZ0FjdGlvbgAAAAIAAAAAAAAAAAAAAAdVcGdyYWRlAAAAAAAAAAAAAAAACURvd25ncmFkZQAAAA==",
+        "AAAAAQAAAAAAAAAAAAAADFRlcm1zVmVyc2lvbgAAAAYAAAAAAAAABmF1dGhvcgAAAAAAEwAAAAAAAAAEYm9uZAAAB9AAAAAEQm9uZAAAAAAAAAAGaXNzdWVyAAAAAAfQAAAABklzc3VlcgAAAAAAAAAAAAZsZWRnZXIAAAAAAAQAAAAAAAAACXRpbWVzdGFtcAAAAAAAAAYAAAAAAAAAB3ZlcnNpb24AAAAABA==",
+        "AAAAAAAAAAAAAAAIYnJlYWNoZXMAAAADAAAAAAAAAARpc2luAAAAEAAAAAAAAAAFc3RhcnQAAAAAAAAEAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6gAAB9AAAAAGQnJlYWNoAAA=",
+        "AAAAAAAAAAAAAAAIY3VycmVuY3kAAAABAAAAAAAAAARpc2luAAAAEAAAAAEAAAAT",
+        "AAAAAAAAAAAAAAAIc2NoZWR1bGUAAAABAAAAAAAAAARpc2luAAAAEAAAAAEAAAfQAAAAFEFtb3J0aXphdGlvblNjaGVkdWxl",
+        "AAAAAAAAAAAAAAAIc2V0X2JvbmQAAAACAAAAAAAAAARpc2luAAAAEAAAAAAAAAAEYm9uZAAAB9AAAAAEQm9uZAAAAAA=",
+        "AAAAAgAAAAAAAAAAAAAADUNvbGxhdGVyYWxSZWYAAAAAAAACAAAAAQAAAAAAAAAHT25DaGFpbgAAAAABAAAAEwAAAAEAAAAAAAAACE9mZkNoYWluAAAAAQAAA+4AAAAg",
+        "AAAAAgAAAAAAAAAAAAAADUNvdmVuYW50Qm91bmQAAAAAAAACAAAAAAAAAAAAAAADTWF4AAAAAAAAAAAAAAAAA01pbgA=",
+        "AAAAAgAAAAAAAAAAAAAADUd1YXJhbnRlZVR5cGUAAAAAAAAEAAAAAAAAAAAAAAANUXVpcm9ncmFmYXJpYQAAAAAAAAAAAAAAAAAABFJlYWwAAAAAAAAAAAAAAAlGbHV0dWFudGUAAAAAAAAAAAAAAAAAAAtTdWJvcmRpbmFkYQA=",
+        "AAAAAAAAAAAAAAAJYm9uZF9pbmZvAAAAAAAAAQAAAAAAAAAEaXNpbgAAABAAAAABAAAH0AAAAARCb25k",
+        "AAAAAAAAAAAAAAAJY292ZW5hbnRzAAAAAAAAAQAAAAAAAAAEaXNpbgAAABAAAAABAAAD6gAAB9AAAAAIQ292ZW5hbnQ=",
+        "AAAAAAAAAAAAAAAJZG9jdW1lbnRzAAAAAAAAAwAAAAAAAAAEaXNpbgAAABAAAAAAAAAABXN0YXJ0AAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAfQAAAACERvY3VtZW50",
+        "AAAAAAAAAAAAAAAJZnJlcXVlbmN5AAAAAAAAAQAAAAAAAAAEaXNpbgAAABAAAAABAAAABg==",
+        "AAAAAAAAAAAAAAAJZ3VhcmFudGVlAAAAAAAAAQAAAAAAAAAEaXNpbgAAABAAAAABAAAD6AAAB9AAAAAJR3VhcmFudGVlAAAA",
+        "AAAAAAAAAAAAAAAJcHJpbmNpcGFsAAAAAAAAAQAAAAAAAAAEaXNpbgAAABAAAAABAAAH0AAAAAlQcmluY2lwYWwAAAA=",
+        "AAAAAAAAAAAAAAAKaXNzdWVfZGF0ZQAAAAAAAQAAAAAAAAAEaXNpbgAAABAAAAABAAAABg==",
+        "AAAAAAAAAAAAAAAKc2V0X2lzc3VlcgAAAAAAAgAAAAAAAAAEaXNpbgAAABAAAAAAAAAABmlzc3VlcgAAAAAH0AAAAAZJc3N1ZXIAAAAAAAA=",
+        "AAAAAAAAAAAAAAALYm9uZF9zdGF0dXMAAAAAAQAAAAAAAAAEaXNpbgAAABAAAAABAAAH0AAAAApCb25kU3RhdHVzAAA=",
+        "AAAAAAAAAAAAAAALaW5zdGl0dXRpb24AAAAAAQAAAAAAAAAEaXNpbgAAABAAAAABAAAAEw==",
+        "AAAAAAAAAAAAAAALaXNzdWVyX2luZm8AAAAAAQAAAAAAAAAEaXNpbgAAABAAAAABAAAH0AAAAAZJc3N1ZXIAAA==",
+        "AAAAAAAAAAAAAAALbGlzdF9zZXJpZXMAAAAAAgAAAAAAAAAFc3RhcnQAAAAAAAAEAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6gAAABA=",
+        "AAAAAAAAAAAAAAALc2V0X3RydXN0ZWUAAAAAAgAAAAAAAAAEaXNpbgAAABAAAAAAAAAAB3RydXN0ZWUAAAAAEwAAAAA=",
+        "AAAAAgAAAAAAAAAAAAAAEEFtb3J0aXphdGlvbktpbmQAAAADAAAAAAAAAAAAAAAGQnVsbGV0AAAAAAAAAAAAAAAAAAZMaW5lYXIAAAAAAAAAAAAAAAAABkN1c3RvbQAA",
+        "AAAAAgAAAAAAAAAAAAAAEEFtb3J0aXphdGlvblBsYW4AAAADAAAAAAAAAAAAAAAGQnVsbGV0AAAAAAABAAAAAAAAAAZMaW5lYXIAAAAAAAEAAAPqAAAABgAAAAEAAAAAAAAABkN1c3RvbQAAAAAAAQAAA+oAAAfQAAAADEFtb3J0aXphdGlvbg==",
+        "AAAAAAAAAAAAAAAMYWRkX2NvdmVuYW50AAAAAgAAAAAAAAAEaXNpbgAAABAAAAAAAAAACGNvdmVuYW50AAAH0AAAAAhDb3ZlbmFudAAAAAEAAAAE",
+        "AAAAAAAAAAAAAAAMYm9uZF9oaXN0b3J5AAAAAwAAAAAAAAAEaXNpbgAAABAAAAAAAAAABXN0YXJ0AAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAfQAAAADFRlcm1zVmVyc2lvbg==",
+        "AAAAAAAAAAAAAAAMYm9uZF9pbmZvX2F0AAAAAgAAAAAAAAAEaXNpbgAAABAAAAAAAAAAB3ZlcnNpb24AAAAABAAAAAEAAAfQAAAADFRlcm1zVmVyc2lvbg==",
+        "AAAAAAAAAAAAAAAMYnJlYWNoX2NvdW50AAAAAQAAAAAAAAAEaXNpbgAAABAAAAABAAAABA==",
+        "AAAAAAAAAAAAAAAMZGVub21pbmF0aW9uAAAAAQAAAAAAAAAEaXNpbgAAABAAAAABAAAACw==",
+        "AAAAAAAAAAAAAAAMbWFya19tYXR1cmVkAAAAAQAAAAAAAAAEaXNpbgAAABAAAAAA",
+        "AAAAAAAAAAAAAAAMcmF0aW5nX2NvdW50AAAAAQAAAAAAAAAEaXNpbgAAABAAAAABAAAABA==",
+        "AAAAAAAAAAAAAAAMcmVtdW5lcmF0aW9uAAAAAQAAAAAAAAAEaXNpbgAAABAAAAABAAAH0AAAAAxSZW11bmVyYXRpb24=",
+        "AAAAAAAAAAAAAAAMc2VyaWVzX2NvdW50AAAAAAAAAAEAAAAE",
+        "AAAAAAAAAAAAAAAMd2FpdmVfYnJlYWNoAAAAAgAAAAAAAAAEaXNpbgAAABAAAAAAAAAACWJyZWFjaF9pZAAAAAAAAAQAAAAA",
+        "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAEAAAAAAAAAB21hbmFnZXIAAAAAEwAAAAA=",
+        "AAAAAAAAAAAAAAANbWFya19yZWRlZW1lZAAAAAAAAAEAAAAAAAAABGlzaW4AAAAQAAAAAA==",
+        "AAAAAAAAAAAAAAANbWF0dXJpdHlfZGF0ZQAAAAAAAAEAAAAAAAAABGlzaW4AAAAQAAAAAQAAAAY=",
+        "AAAAAAAAAAAAAAANb3Blbl9vZmZlcmluZwAAAAAAAAEAAAAAAAAABGlzaW4AAAAQAAAAAA==",
+        "AAAAAAAAAAAAAAANcmVwb3J0X2JyZWFjaAAAAAAAAAMAAAAAAAAABGlzaW4AAAAQAAAAAAAAAAtjb3ZlbmFudF9pZAAAAAAEAAAAAAAAAAhvYnNlcnZlZAAAAAsAAAABAAAABA==",
+        "AAAAAAAAAAAAAAANc2V0X2d1YXJhbnRlZQAAAAAAAAUAAAAAAAAABGlzaW4AAAAQAAAAAAAAAA5ndWFyYW50ZWVfdHlwZQAAAAAH0AAAAA1HdWFyYW50ZWVUeXBlAAAAAAAAAAAAAApjb2xsYXRlcmFsAAAAAAPqAAAH0AAAAApDb2xsYXRlcmFsAAAAAAAAAAAADmNvdmVyYWdlX3JhdGlvAAAAAAALAAAAAAAAAApndWFyYW50b3JzAAAAAAPqAAAAEwAAAAA=",
+        "AAAAAAAAAAAAAAANdmVyc2lvbl9jb3VudAAAAAAAAAEAAAAAAAAABGlzaW4AAAAQAAAAAQAAAAQ=",
+        "AAAAAAAAAAAAAAAOY2xvc2Vfb2ZmZXJpbmcAAAAAAAIAAAAAAAAABGlzaW4AAAAQAAAAAAAAABBwbGFjZWRfcHJpbmNpcGFsAAAACwAAAAA=",
+        "AAAAAAAAAAAAAAAOY3VycmVudF9yYXRpbmcAAAAAAAEAAAAAAAAABGlzaW4AAAAQAAAAAQAAA+gAAAfQAAAABlJhdGluZwAA",
+        "AAAAAAAAAAAAAAAOZG9jdW1lbnRfY291bnQAAAAAAAEAAAAAAAAABGlzaW4AAAAQAAAAAQAAAAQ=",
+        "AAAAAAAAAAAAAAAOcHVibGlzaF9yYXRpbmcAAAAAAAIAAAAAAAAABGlzaW4AAAAQAAAAAAAAAAZyYXRpbmcAAAAAB9AAAAAGUmF0aW5nAAAAAAAA",
+        "AAAAAAAAAAAAAAAOcmF0aW5nX2hpc3RvcnkAAAAAAAMAAAAAAAAABGlzaW4AAAAQAAAAAAAAAAVzdGFydAAAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPqAAAH0AAAAAZSYXRpbmcAAA==",
+        "AAAAAAAAAAAAAAAOc2VyaWVzX21hbmFnZXIAAAAAAAEAAAAAAAAABGlzaW4AAAAQAAAAAQAAABM=",
+        "AAAAAAAAAAAAAAAPYW5jaG9yX2RvY3VtZW50AAAAAAUAAAAAAAAABGlzaW4AAAAQAAAAAAAAAAhkb2NfdHlwZQAAB9AAAAAMRG9jdW1lbnRUeXBlAAAAAAAAAARoYXNoAAAD7gAAACAAAAAAAAAAA3VyaQAAAAAQAAAAAAAAAA5lZmZlY3RpdmVfZGF0ZQAAAAAABgAAAAEAAAAE",
+        "AAAAAAAAAAAAAAAPcmVnaXN0ZXJfc2VyaWVzAAAAAAMAAAAAAAAABGlzaW4AAAAQAAAAAAAAAARib25kAAAH0AAAAARCb25kAAAAAAAAAAZpc3N1ZXIAAAAAB9AAAAAGSXNzdWVyAAAAAAAA",
+        "AAAAAQAAAAAAAAAAAAAAFEFtb3J0aXphdGlvblNjaGVkdWxlAAAAAgAAAAAAAAAEa2luZAAAB9AAAAAQQW1vcnRpemF0aW9uS2luZAAAAAAAAAAIcGF5bWVudHMAAAPqAAAH0AAAAAxBbW9ydGl6YXRpb24=",
+        "AAAAAAAAAAAAAAAQaXNfcmF0aW5nX2FnZW5jeQAAAAEAAAAAAAAABmFnZW5jeQAAAAAAEwAAAAEAAAAB",
+        "AAAAAAAAAAAAAAAQc2V0X2Ftb3J0aXphdGlvbgAAAAIAAAAAAAAABGlzaW4AAAAQAAAAAAAAAARwbGFuAAAH0AAAABBBbW9ydGl6YXRpb25QbGFuAAAAAA==",
+        "AAAAAAAAAAAAAAARbmV4dF9wYXltZW50X2RhdGUAAAAAAAABAAAAAAAAAARpc2luAAAAEAAAAAEAAAPoAAAABg==",
+        "AAAAAAAAAAAAAAARc2V0X3JhdGluZ19hZ2VuY3kAAAAAAAACAAAAAAAAAAZhZ2VuY3kAAAAAABMAAAAAAAAAB2VuYWJsZWQAAAAAAQAAAAA=",
+        "AAAAAAAAAAAAAAASc2V0X3Nlcmllc19tYW5hZ2VyAAAAAAACAAAAAAAAAARpc2luAAAAEAAAAAAAAAAHbWFuYWdlcgAAAAATAAAAAA==",
+        "AAAAAAAAAAAAAAAWZGVjbGFyZV9lYXJseV9tYXR1cml0eQAAAAAAAgAAAAAAAAAEaXNpbgAAABAAAAAAAAAACWJyZWFjaF9pZAAAAAAAAAQAAAAA",
+        "AAAAAAAAAAAAAAAYcmVjb3JkX3ByaW5jaXBhbF9wYXltZW50AAAAAgAAAAAAAAAEaXNpbgAAABAAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
+        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAGQAAAAAAAAAXU2VyaWVzQWxyZWFkeVJlZ2lzdGVyZWQAAAAAAgAAAAAAAAAOU2VyaWVzTm90Rm91bmQAAAAAAAMAAAAAAAAAC0ludmFsaWRJc2luAAAAAAQAAAAAAAAAC0ludmFsaWRDbnBqAAAAAAUAAAAAAAAACkludmFsaWRMZWkAAAAAAAYAAAAAAAAAF0ludmFsaWRTdGF0dXNUcmFuc2l0aW9uAAAAAAcAAAAAAAAADU5vdFlldE1hdHVyZWQAAAAAAAAIAAAAAAAAABRQcmluY2lwYWxPdXRzdGFuZGluZwAAAAkAAAAAAAAADUludmFsaWRBbW91bnQAAAAAAAAKAAAAAAAAABhJbmRlbnR1cmVBbHJlYWR5QW5jaG9yZWQAAAALAAAAAAAAABBNaXNzaW5nSW5kZW50dXJlAAAADAAAAAAAAAARRHVwbGljYXRlRG9jdW1lbnQAAAAAAAANAAAAAAAAAA9WZXJzaW9uTm90Rm91bmQAAAAADgAAAAAAAAATSW52YWxpZFJlbXVuZXJhdGlvbgAAAAAPAAAAAAAAAA9JbnZhbGlkU2NoZWR1bGUAAAAAEAAAAAAAAAAQU2NoZWR1bGVOb3RGb3VuZAAAABEAAAAAAAAADVRydXN0ZWVOb3RTZXQAAAAAAAASAAAAAAAAABBDb3ZlbmFudE5vdEZvdW5kAAAAEwAAAAAAAAAKTm90QUJyZWFjaAAAAAAAFAAAAAAAAAAOQnJlYWNoTm90Rm91bmQAAAAAABUAAAAAAAAAEEJyZWFjaE5vdFBlbmRpbmcAAAAWAAAAAAAAAA9Ob3RSYXRpbmdBZ2VuY3kAAAAAFwAAAAAAAAANSW52YWxpZFJhdGluZwAAAAAAABgAAAAAAAAAEEludmFsaWRHdWFyYW50ZWUAAAAZAAAAAAAAABBUb29NYW55Q292ZW5hbnRzAAAAGg==",
+        "AAAABQAAAAAAAAAAAAAAEkVhcmx5TWF0dXJpdHlFdmVudAAAAAAAAQAAAA5lYXJseV9tYXR1cml0eQAAAAAAAgAAAAAAAAAEaXNpbgAAABAAAAABAAAAAAAAAAlicmVhY2hfaWQAAAAAAAAEAAAAAAAAAAA=",
+        "AAAABQAAAAAAAAAAAAAAElByaW5jaXBhbFBhaWRFdmVudAAAAAAAAQAAAA5wcmluY2lwYWxfcGFpZAAAAAAAAwAAAAAAAAAEaXNpbgAAABAAAAABAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAAAAAAp0b3RhbF9wYWlkAAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAElJhdGluZ0NoYW5nZWRFdmVudAAAAAAAAQAAAA1yYXRpbmdfY2hhbmdlAAAAAAAABQAAAAAAAAAEaXNpbgAAABAAAAABAAAAAAAAAAZhZ2VuY3kAAAAAABMAAAABAAAAAAAAAAZhY3Rpb24AAAAAB9AAAAAMUmF0aW5nQWN0aW9uAAAAAAAAAAAAAAAOcHJldmlvdXNfZ3JhZGUAAAAAABAAAAAAAAAAAAAAAAVncmFkZQAAAAAAABAAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAElN0YXR1c0NoYW5nZWRFdmVudAAAAAAAAQAAAAZzdGF0dXMAAAAAAAMAAAAAAAAABGlzaW4AAAAQAAAAAQAAAAAAAAAEZnJvbQAAB9AAAAAKQm9uZFN0YXR1cwAAAAAAAAAAAAAAAAACdG8AAAAAB9AAAAAKQm9uZFN0YXR1cwAAAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAE0JyZWFjaFJlcG9ydGVkRXZlbnQAAAAAAQAAAAZicmVhY2gAAAAAAAQAAAAAAAAABGlzaW4AAAAQAAAAAQAAAAAAAAAJYnJlYWNoX2lkAAAAAAAABAAAAAAAAAAAAAAAC2NvdmVuYW50X2lkAAAAAAQAAAAAAAAAAAAAAAhvYnNlcnZlZAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAFURvY3VtZW50QW5jaG9yZWRFdmVudAAAAAAAAAEAAAAIZG9jdW1lbnQAAAAEAAAAAAAAAARpc2luAAAAEAAAAAEAAAAAAAAABWluZGV4AAAAAAAABAAAAAAAAAAAAAAACGRvY190eXBlAAAH0AAAAAxEb2N1bWVudFR5cGUAAAAAAAAAAAAAAARoYXNoAAAD7gAAACAAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAFUd1YXJhbnRlZVVwZGF0ZWRFdmVudAAAAAAAAAEAAAAJZ3VhcmFudGVlAAAAAAAAAwAAAAAAAAAEaXNpbgAAABAAAAABAAAAAAAAAA5ndWFyYW50ZWVfdHlwZQAAAAAH0AAAAA1HdWFyYW50ZWVUeXBlAAAAAAAAAAAAAAAAAAAOY292ZXJhZ2VfcmF0aW8AAAAAAAsAAAAAAAAAAg==",
+        "AAAAAQAAAAAAAAAAAAAABEJvbmQAAAAIAAAAAAAAAAtib25kX3N0YXR1cwAAAAfQAAAACkJvbmRTdGF0dXMAAAAAAAAAAAAIY3VycmVuY3kAAAATAAAAAAAAAAxkZW5vbWluYXRpb24AAAALAAAAAAAAAAlmcmVxdWVuY3kAAAAAAAAGAAAAAAAAAAppc3N1ZV9kYXRlAAAAAAAGAAAAAAAAAAxpc3N1ZV9udW1iZXIAAAAGAAAAAAAAAA1tYXR1cml0eV9kYXRlAAAAAAAABgAAAAAAAAAMcmVtdW5lcmF0aW9uAAAH0AAAAAxSZW11bmVyYXRpb24=",
+        "AAAAAgAAAAAAAAAAAAAAB0luZGV4ZXIAAAAAAwAAAAAAAAAAAAAACVByZWZpeGFkbwAAAAAAAAAAAAAAAAAAA0NkaQAAAAAAAAAAAAAAAARJcGNh",
+        "AAAAAgAAAAAAAAAAAAAACERheUNvdW50AAAAAwAAAAAAAAAAAAAABUR1MjUyAAAAAAAAAAAAAAAAAAAJVGhpcnR5MzYwAAAAAAAAAAAAAAAAAAAGQWN0MzY1AAA=",
+        "AAAAAQAAAAAAAAAAAAAACUJvbmRSYW5nZQAAAAAAAAIAAAAAAAAACGZpcnN0X2lkAAAABgAAAAAAAAAIcXVhbnRpdHkAAAAG",
         "AAAAAQAAAAAAAAAAAAAACUlzc3VlRGF0YQAAAAAAAAIAAAAAAAAACGludmVzdG9yAAAAEwAAAAAAAAAJcHJpbmNpcGFsAAAAAAAABg==",
-        "AAAAAgAAAAAAAAAAAAAACkJvbmRTdGF0dXMAAAAAAAQAAAAAAAAAAAAAAAdPZmZlcmVkAAAAAAAAAAAAAAAABklzc3VlZAAAAAAAAAAAAAAAAAAHTWF0dXJlZAAAAAAAAAAAAAAAAAhSZWRlZW1lZA==",
-        "AAAAAAAAAAAAAAAKaW5pdGlhbGl6ZQAAAAAAAQAAAAAAAAAHbWFuYWdlcgAAAAATAAAAAA==",
-        "AAAAAAAAAAAAAAAIc2V0X2JvbmQAAAABAAAAAAAAAARib25kAAAH0AAAAARCb25kAAAAAA==",
-        "AAAAAAAAAAAAAAAKc2V0X2lzc3VlcgAAAAAAAQAAAAAAAAAGaXNzdWVyAAAAAAfQAAAABklzc3VlcgAAAAAAAA==",
-        "AAAAAAAAAAAAAAALaW5zdGl0dXRpb24AAAAAAAAAAAEAAAAT",
-        "AAAAAAAAAAAAAAAEY25wagAAAAAAAAABAAAAEA==",
-        "AAAAAAAAAAAAAAADbGVpAAAAAAAAAAABAAAAEA==",
-        "AAAAAAAAAAAAAAAJYm9uZF9pbmZvAAAAAAAAAAAAAAEAAAfQAAAABEJvbmQ=",
-        "AAAAAAAAAAAAAAAIY3VycmVuY3kAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAAAAAAAMZGVub21pbmF0aW9uAAAAAAAAAAEAAAAL",
-        "AAAAAAAAAAAAAAAJZnJlcXVlbmN5AAAAAAAAAAAAAAEAAAAG",
-        "AAAAAAAAAAAAAAANaW50ZXJlc3RfcmF0ZQAAAAAAAAAAAAABAAAACw==",
-        "AAAAAAAAAAAAAAAKaXNzdWVfZGF0ZQAAAAAAAAAAAAEAAAAG",
-        "AAAAAAAAAAAAAAANbWF0dXJpdHlfZGF0ZQAAAAAAAAAAAAABAAAABg==",
-        "AAAAAAAAAAAAAAALaXNzdWVyX2luZm8AAAAAAAAAAAEAAAfQAAAABklzc3VlcgAA" ]),
+        "AAAAAgAAAAAAAAAAAAAACkJvbmRTdGF0dXMAAAAAAAUAAAAAAAAAAAAAAApSZWdpc3RlcmVkAAAAAAAAAAAAAAAAAAdPZmZlcmVkAAAAAAAAAAAAAAAABklzc3VlZAAAAAAAAAAAAAAAAAAHTWF0dXJlZAAAAAAAAAAAAAAAAAhSZWRlZW1lZA==",
+        "AAAAAQAAAAAAAAAAAAAADFJlbXVuZXJhdGlvbgAAAAQAAAAAAAAACWRheV9jb3VudAAAAAAAB9AAAAAIRGF5Q291bnQAAAAAAAAAEGluZGV4X3BlcmNlbnRhZ2UAAAALAAAAAAAAAAdpbmRleGVyAAAAB9AAAAAHSW5kZXhlcgAAAAAAAAAABnNwcmVhZAAAAAAACw==" ]),
       options
     )
   }
   public readonly fromJSON = {
-    initialize: this.txFromJSON<null>,
-        set_bond: this.txFromJSON<null>,
-        set_issuer: this.txFromJSON<null>,
-        institution: this.txFromJSON<string>,
+    lei: this.txFromJSON<string>,
         cnpj: this.txFromJSON<string>,
-        lei: this.txFromJSON<string>,
-        bond_info: this.txFromJSON<Bond>,
+        breach: this.txFromJSON<Breach>,
+        trustee: this.txFromJSON<Option<string>>,
+        breaches: this.txFromJSON<Array<Breach>>,
         currency: this.txFromJSON<string>,
-        denomination: this.txFromJSON<i128>,
+        schedule: this.txFromJSON<AmortizationSchedule>,
+        set_bond: this.txFromJSON<null>,
+        bond_info: this.txFromJSON<Bond>,
+        covenants: this.txFromJSON<Array<Covenant>>,
+        documents: this.txFromJSON<Array<Document>>,
         frequency: this.txFromJSON<u64>,
-        interest_rate: this.txFromJSON<i128>,
+        guarantee: this.txFromJSON<Option<Guarantee>>,
+        principal: this.txFromJSON<Principal>,
         issue_date: this.txFromJSON<u64>,
+        set_issuer: this.txFromJSON<null>,
+        bond_status: this.txFromJSON<BondStatus>,
+        institution: this.txFromJSON<string>,
+        issuer_info: this.txFromJSON<Issuer>,
+        list_series: this.txFromJSON<Array<string>>,
+        set_trustee: this.txFromJSON<null>,
+        add_covenant: this.txFromJSON<u32>,
+        bond_history: this.txFromJSON<Array<TermsVersion>>,
+        bond_info_at: this.txFromJSON<TermsVersion>,
+        breach_count: this.txFromJSON<u32>,
+        denomination: this.txFromJSON<i128>,
+        mark_matured: this.txFromJSON<null>,
+        rating_count: this.txFromJSON<u32>,
+        remuneration: this.txFromJSON<Remuneration>,
+        series_count: this.txFromJSON<u32>,
+        waive_breach: this.txFromJSON<null>,
+        mark_redeemed: this.txFromJSON<null>,
         maturity_date: this.txFromJSON<u64>,
-        issuer_info: this.txFromJSON<Issuer>
+        open_offering: this.txFromJSON<null>,
+        report_breach: this.txFromJSON<u32>,
+        set_guarantee: this.txFromJSON<null>,
+        version_count: this.txFromJSON<u32>,
+        close_offering: this.txFromJSON<null>,
+        current_rating: this.txFromJSON<Option<Rating>>,
+        document_count: this.txFromJSON<u32>,
+        publish_rating: this.txFromJSON<null>,
+        rating_history: this.txFromJSON<Array<Rating>>,
+        series_manager: this.txFromJSON<string>,
+        anchor_document: this.txFromJSON<u32>,
+        register_series: this.txFromJSON<null>,
+        is_rating_agency: this.txFromJSON<boolean>,
+        set_amortization: this.txFromJSON<null>,
+        next_payment_date: this.txFromJSON<Option<u64>>,
+        set_rating_agency: this.txFromJSON<null>,
+        set_series_manager: this.txFromJSON<null>,
+        declare_early_maturity: this.txFromJSON<null>,
+        record_principal_payment: this.txFromJSON<null>
   }
 }
\ No newline at end of file
@@ -38,9 +38,67 @@ export const networks = {
   }
 } as const
 
-export type DataKey = {tag: "Count", values: readonly [string]} | {tag: "Bonds", values: readonly [string]};
+export type DataKey = {tag: "Count", values: readonly [string]} | {tag: "RangeCount", values: readonly [string]} | {tag: "Range", values: readonly [string, u32]};
+
+
+export interface Bond {
+  bond_status: BondStatus;
+  currency: string;
+  denomination: i128;
+  frequency: u64;
+  issue_date: u64;
+  issue_number: u64;
+  maturity_date: u64;
+  remuneration: Remuneration;
+}
+
+export type Indexer = {tag: "Prefixado", values: void} | {tag: "Cdi", values: void} | {tag: "Ipca", values: void};
+
+export type DayCount = {tag: "Du252", values: void} | {tag: "Thirty360", values: void} | {tag: "Act365", values: void};
+
+
+export interface BondRange {
+  first_id: u64;
+  quantity: u64;
+}
+
+
+export interface IssueData {
+  investor: string;
+  principal: u64;
+}
+
+export type BondStatus = {tag: "Registered", values: void} | {tag: "Offered", values: void} | {tag: "Issued", values: void} | {tag: "Matured", values: void} | {tag: "Redeemed", values: void};
+
+
+export interface Remuneration {
+  day_count: DayCount;
+  index_percentage: i128;
+  indexer: Indexer;
+  spread: i128;
+}
 
 export interface Client {
+  /**
+   * Construct and simulate a deposit_lot transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  deposit_lot: ({user, first_id, quantity}: {user: string, first_id: u64, quantity: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
   /**
    * Construct and simulate a deposit_bond transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
@@ -61,6 +119,26 @@ export interface Client {
     simulate?: boolean;
   }) => Promise<AssembledTransaction<null>>
 
+  /**
+   * Construct and simulate a get_bond_ranges_custody transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_bond_ranges_custody: ({user, start, limit}: {user: string, start: u32, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<BondRange>>>
+
   /**
    * Construct and simulate a get_total_bonds_deposited transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
@@ -84,7 +162,7 @@ export interface Client {
   /**
    * Construct and simulate a get_bonds_position_custody transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_bonds_position_custody: ({user}: {user: string}, options?: {
+  get_bonds_position_custody: ({user, start, limit}: {user: string, start: u32, limit: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -119,15 +197,26 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAgAAAAEAAAAAAAAABUNvdW50AAAAAAAAAQAAABMAAAABAAAAAAAAAAVCb25kcwAAAAAAAAEAAAAT",
+      new ContractSpec([ "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAwAAAAEAAAAAAAAABUNvdW50AAAAAAAAAQAAABMAAAABAAAAAAAAAApSYW5nZUNvdW50AAAAAAABAAAAEwAAAAEAAAAAAAAABVJhbmdlAAAAAAAAAgAAABMAAAAE",
+        "AAAAAAAAAAAAAAALZGVwb3NpdF9sb3QAAAAAAwAAAAAAAAAEdXNlcgAAABMAAAAAAAAACGZpcnN0X2lkAAAABgAAAAAAAAAIcXVhbnRpdHkAAAAGAAAAAA==",
         "AAAAAAAAAAAAAAAMZGVwb3NpdF9ib25kAAAAAgAAAAAAAAAEdXNlcgAAABMAAAAAAAAAB2JvbmRfaWQAAAAABgAAAAA=",
+        "AAAAAAAAAAAAAAAXZ2V0X2JvbmRfcmFuZ2VzX2N1c3RvZHkAAAAAAwAAAAAAAAAEdXNlcgAAABMAAAAAAAAABXN0YXJ0AAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAfQAAAACUJvbmRSYW5nZQAAAA==",
         "AAAAAAAAAAAAAAAZZ2V0X3RvdGFsX2JvbmRzX2RlcG9zaXRlZAAAAAAAAAEAAAAAAAAABHVzZXIAAAATAAAAAQAAAAY=",
-        "AAAAAAAAAAAAAAAaZ2V0X2JvbmRzX3Bvc2l0aW9uX2N1c3RvZHkAAAAAAAEAAAAAAAAABHVzZXIAAAATAAAAAQAAA+oAAAAG" ]),
+        "AAAAAAAAAAAAAAAaZ2V0X2JvbmRzX3Bvc2l0aW9uX2N1c3RvZHkAAAAAAAMAAAAAAAAABHVzZXIAAAATAAAAAAAAAAVzdGFydAAAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPqAAAABg==",
+        "AAAAAQAAAAAAAAAAAAAABEJvbmQAAAAIAAAAAAAAAAtib25kX3N0YXR1cwAAAAfQAAAACkJvbmRTdGF0dXMAAAAAAAAAAAAIY3VycmVuY3kAAAATAAAAAAAAAAxkZW5vbWluYXRpb24AAAALAAAAAAAAAAlmcmVxdWVuY3kAAAAAAAAGAAAAAAAAAAppc3N1ZV9kYXRlAAAAAAAGAAAAAAAAAAxpc3N1ZV9udW1iZXIAAAAGAAAAAAAAAA1tYXR1cml0eV9kYXRlAAAAAAAABgAAAAAAAAAMcmVtdW5lcmF0aW9uAAAH0AAAAAxSZW11bmVyYXRpb24=",
+        "AAAAAgAAAAAAAAAAAAAAB0luZGV4ZXIAAAAAAwAAAAAAAAAAAAAACVByZWZpeGFkbwAAAAAAAAAAAAAAAAAAA0NkaQAAAAAAAAAAAAAAAARJcGNh",
+        "AAAAAgAAAAAAAAAAAAAACERheUNvdW50AAAAAwAAAAAAAAAAAAAABUR1MjUyAAAAAAAAAAAAAAAAAAAJVGhpcnR5MzYwAAAAAAAAAAAAAAAAAAAGQWN0MzY1AAA=",
+        "AAAAAQAAAAAAAAAAAAAACUJvbmRSYW5nZQAAAAAAAAIAAAAAAAAACGZpcnN0X2lkAAAABgAAAAAAAAAIcXVhbnRpdHkAAAAG",
+        "AAAAAQAAAAAAAAAAAAAACUlzc3VlRGF0YQAAAAAAAAIAAAAAAAAACGludmVzdG9yAAAAEwAAAAAAAAAJcHJpbmNpcGFsAAAAAAAABg==",
+        "AAAAAgAAAAAAAAAAAAAACkJvbmRTdGF0dXMAAAAAAAUAAAAAAAAAAAAAAApSZWdpc3RlcmVkAAAAAAAAAAAAAAAAAAdPZmZlcmVkAAAAAAAAAAAAAAAABklzc3VlZAAAAAAAAAAAAAAAAAAHTWF0dXJlZAAAAAAAAAAAAAAAAAhSZWRlZW1lZA==",
+        "AAAAAQAAAAAAAAAAAAAADFJlbXVuZXJhdGlvbgAAAAQAAAAAAAAACWRheV9jb3VudAAAAAAAB9AAAAAIRGF5Q291bnQAAAAAAAAAEGluZGV4X3BlcmNlbnRhZ2UAAAALAAAAAAAAAAdpbmRleGVyAAAAB9AAAAAHSW5kZXhlcgAAAAAAAAAABnNwcmVhZAAAAAAACw==" ]),
       options
     )
   }
   public readonly fromJSON = {
-    deposit_bond: this.txFromJSON<null>,
+    deposit_lot: this.txFromJSON<null>,
+        deposit_bond: this.txFromJSON<null>,
+        get_bond_ranges_custody: this.txFromJSON<Array<BondRange>>,
         get_total_bonds_deposited: this.txFromJSON<u64>,
         get_bonds_position_custody: this.txFromJSON<Array<u64>>
   }
@@ -39,15 +39,39 @@ export const networks = {
 } as const
 
 
+export interface Lot {
+  bond: Bond;
+  first_id: u64;
+  investor: string;
+  quantity: u64;
+}
+
+
+export interface TokenMinting {
+  custody_contract: string;
+  max_total_supply: u64;
+}
+
+
 export interface Bond {
   bond_status: BondStatus;
   currency: string;
   denomination: i128;
   frequency: u64;
-  interest_rate: i128;
   issue_date: u64;
   issue_number: u64;
   maturity_date: u64;
+  remuneration: Remuneration;
+}
+
+export type Indexer = {tag: "Prefixado", values: void} | {tag: "Cdi", values: void} | {tag: "Ipca", values: void};
+
+export type DayCount = {tag: "Du252", values: void} | {tag: "Thirty360", values: void} | {tag: "Act365", values: void};
+
+
+export interface BondRange {
+  first_id: u64;
+  quantity: u64;
 }
 
 
@@ -56,19 +80,21 @@ export interface IssueData {
   principal: u64;
 }
 
-export type BondStatus = {tag: "Offered", values: void} | {tag: "Issued", values: void} | {tag: "Matured", values: void} | {tag: "Redeemed", values: void};
+export type BondStatus = {tag: "Registered", values: void} | {tag: "Offered", values: void} | {tag: "Issued", values: void} | {tag: "Matured", values: void} | {tag: "Redeemed", values: void};
 
 
-export interface TokenMinting {
-  custody_contract: string;
-  max_total_supply: u64;
+export interface Remuneration {
+  day_count: DayCount;
+  index_percentage: i128;
+  indexer: Indexer;
+  spread: i128;
 }
 
 export interface Client {
   /**
-   * Construct and simulate a initialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a bond_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  initialize: ({custody_contract}: {custody_contract: string}, options?: {
+  bond_status: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -83,7 +109,27 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<BondStatus>>
+
+  /**
+   * Construct and simulate a is_operator transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_operator: ({owner, operator}: {owner: string, operator: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<boolean>>
 
   /**
    * Construct and simulate a mint_tokens transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -106,9 +152,29 @@ export interface Client {
   }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a transfer_bond_ownership transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a issue_volume transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  transfer_bond_ownership: ({bond_id, new_investor}: {bond_id: u64, new_investor: string}, options?: {
+  issue_volume: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a set_operator transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_operator: ({owner, operator, approved}: {owner: string, operator: string, approved: boolean}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -145,10 +211,30 @@ export interface Client {
     simulate?: boolean;
   }) => Promise<AssembledTransaction<Bond>>
 
+  /**
+   * Construct and simulate a operator_transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  operator_transfer: ({operator, bond_id, new_investor}: {operator: string, bond_id: u64, new_investor: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
   /**
    * Construct and simulate a get_investor_bonds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_investor_bonds: ({investor}: {investor: string}, options?: {
+  get_investor_bonds: ({investor, start, limit}: {investor: string, start: u32, limit: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -166,9 +252,9 @@ export interface Client {
   }) => Promise<AssembledTransaction<Array<u64>>>
 
   /**
-   * Construct and simulate a get_investor_by_bond_id transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_investor_ranges transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_investor_by_bond_id: ({bond_id}: {bond_id: u64}, options?: {
+  get_investor_ranges: ({investor, start, limit}: {investor: string, start: u32, limit: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -183,12 +269,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>
+  }) => Promise<AssembledTransaction<Array<BondRange>>>
 
   /**
-   * Construct and simulate a issue_volume transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_investor_by_bond_id transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  issue_volume: (options?: {
+  get_investor_by_bond_id: ({bond_id}: {bond_id: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -203,12 +289,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+  }) => Promise<AssembledTransaction<string>>
 
   /**
-   * Construct and simulate a set_bond_template transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a transfer_bond_ownership transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  set_bond_template: ({bond_template}: {bond_template: Bond}, options?: {
+  transfer_bond_ownership: ({bond_id, new_investor}: {bond_id: u64, new_investor: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -228,6 +314,8 @@ export interface Client {
 }
 export class Client extends ContractClient {
   static async deploy<T = Client>(
+        /** Constructor/Initialization Args for the contract's `__constructor` method */
+        {custody_contract, bond_storage, isin}: {custody_contract: string, bond_storage: string, isin: string},
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions &
       Omit<ContractClientOptions, "contractId"> & {
@@ -239,33 +327,46 @@ export class Client extends ContractClient {
         format?: "hex" | "base64";
       }
   ): Promise<AssembledTransaction<T>> {
-    return ContractClient.deploy(null, options)
+    return ContractClient.deploy({custody_contract, bond_storage, isin}, options)
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEJvbmQAAAAIAAAAAAAAAAtib25kX3N0YXR1cwAAAAfQAAAACkJvbmRTdGF0dXMAAAAAAAAAAAAIY3VycmVuY3kAAAATAAAAAAAAAAxkZW5vbWluYXRpb24AAAALAAAAAAAAAAlmcmVxdWVuY3kAAAAAAAAGAAAAAAAAAA1pbnRlcmVzdF9yYXRlAAAAAAAACwAAAAAAAAAKaXNzdWVfZGF0ZQAAAAAABgAAAAAAAAAMaXNzdWVfbnVtYmVyAAAABgAAAAAAAAANbWF0dXJpdHlfZGF0ZQAAAAAAAAY=",
-        "AAAAAQAAAAAAAAAAAAAACUlzc3VlRGF0YQAAAAAAAAIAAAAAAAAACGludmVzdG9yAAAAEwAAAAAAAAAJcHJpbmNpcGFsAAAAAAAABg==",
-        "AAAAAgAAAAAAAAAAAAAACkJvbmRTdGF0dXMAAAAAAAQAAAAAAAAAAAAAAAdPZmZlcmVkAAAAAAAAAAAAAAAABklzc3VlZAAAAAAAAAAAAAAAAAAHTWF0dXJlZAAAAAAAAAAAAAAAAAhSZWRlZW1lZA==",
+      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAAA0xvdAAAAAAEAAAAAAAAAARib25kAAAH0AAAAARCb25kAAAAAAAAAAhmaXJzdF9pZAAAAAYAAAAAAAAACGludmVzdG9yAAAAEwAAAAAAAAAIcXVhbnRpdHkAAAAG",
+        "AAAABQAAAAAAAAAAAAAAC0JvbmRzSXNzdWVkAAAAAAEAAAALQm9uZHNJc3N1ZWQAAAAAAwAAAAAAAAAIaW52ZXN0b3IAAAATAAAAAQAAAAAAAAAIZmlyc3RfaWQAAAAGAAAAAAAAAAAAAAAIcXVhbnRpdHkAAAAGAAAAAAAAAAE=",
         "AAAAAQAAAAAAAAAAAAAADFRva2VuTWludGluZwAAAAIAAAAAAAAAEGN1c3RvZHlfY29udHJhY3QAAAATAAAAAAAAABBtYXhfdG90YWxfc3VwcGx5AAAABg==",
-        "AAAAAAAAAAAAAAAKaW5pdGlhbGl6ZQAAAAAAAQAAAAAAAAAQY3VzdG9keV9jb250cmFjdAAAABMAAAAA",
+        "AAAAAAAAAAAAAAALYm9uZF9zdGF0dXMAAAAAAAAAAAEAAAfQAAAACkJvbmRTdGF0dXMAAA==",
+        "AAAAAAAAAAAAAAALaXNfb3BlcmF0b3IAAAAAAgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAhvcGVyYXRvcgAAABMAAAABAAAAAQ==",
         "AAAAAAAAAAAAAAALbWludF90b2tlbnMAAAAAAgAAAAAAAAAFcGF5ZXIAAAAAAAATAAAAAAAAABRudW1iZXJfb2ZfZGViZW50dXJlcwAAAAYAAAAA",
-        "AAAAAAAAAAAAAAAXdHJhbnNmZXJfYm9uZF9vd25lcnNoaXAAAAAAAgAAAAAAAAAHYm9uZF9pZAAAAAAGAAAAAAAAAAxuZXdfaW52ZXN0b3IAAAATAAAAAA==",
+        "AAAAAAAAAAAAAAAMaXNzdWVfdm9sdW1lAAAAAAAAAAEAAAAG",
+        "AAAAAAAAAAAAAAAMc2V0X29wZXJhdG9yAAAAAwAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAhvcGVyYXRvcgAAABMAAAAAAAAACGFwcHJvdmVkAAAAAQAAAAA=",
+        "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAMAAAAAAAAAEGN1c3RvZHlfY29udHJhY3QAAAATAAAAAAAAAAxib25kX3N0b3JhZ2UAAAATAAAAAAAAAARpc2luAAAAEAAAAAA=",
         "AAAAAAAAAAAAAAAQZ2V0X2JvbmRfZGV0YWlscwAAAAEAAAAAAAAAB2JvbmRfaWQAAAAABgAAAAEAAAfQAAAABEJvbmQ=",
-        "AAAAAAAAAAAAAAASZ2V0X2ludmVzdG9yX2JvbmRzAAAAAAABAAAAAAAAAAhpbnZlc3RvcgAAABMAAAABAAAD6gAAAAY=",
+        "AAAAAAAAAAAAAAARb3BlcmF0b3JfdHJhbnNmZXIAAAAAAAADAAAAAAAAAAhvcGVyYXRvcgAAABMAAAAAAAAAB2JvbmRfaWQAAAAABgAAAAAAAAAMbmV3X2ludmVzdG9yAAAAEwAAAAA=",
+        "AAAAAAAAAAAAAAASZ2V0X2ludmVzdG9yX2JvbmRzAAAAAAADAAAAAAAAAAhpbnZlc3RvcgAAABMAAAAAAAAABXN0YXJ0AAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAAG",
+        "AAAAAAAAAAAAAAATZ2V0X2ludmVzdG9yX3JhbmdlcwAAAAADAAAAAAAAAAhpbnZlc3RvcgAAABMAAAAAAAAABXN0YXJ0AAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAfQAAAACUJvbmRSYW5nZQAAAA==",
         "AAAAAAAAAAAAAAAXZ2V0X2ludmVzdG9yX2J5X2JvbmRfaWQAAAAAAQAAAAAAAAAHYm9uZF9pZAAAAAAGAAAAAQAAABM=",
-        "AAAAAAAAAAAAAAAMaXNzdWVfdm9sdW1lAAAAAAAAAAEAAAAG",
-        "AAAAAAAAAAAAAAARc2V0X2JvbmRfdGVtcGxhdGUAAAAAAAABAAAAAAAAAA1ib25kX3RlbXBsYXRlAAAAAAAH0AAAAARCb25kAAAAAA==" ]),
+        "AAAAAAAAAAAAAAAXdHJhbnNmZXJfYm9uZF9vd25lcnNoaXAAAAAAAgAAAAAAAAAHYm9uZF9pZAAAAAAGAAAAAAAAAAxuZXdfaW52ZXN0b3IAAAATAAAAAA==",
+        "AAAAAQAAAAAAAAAAAAAABEJvbmQAAAAIAAAAAAAAAAtib25kX3N0YXR1cwAAAAfQAAAACkJvbmRTdGF0dXMAAAAAAAAAAAAIY3VycmVuY3kAAAATAAAAAAAAAAxkZW5vbWluYXRpb24AAAALAAAAAAAAAAlmcmVxdWVuY3kAAAAAAAAGAAAAAAAAAAppc3N1ZV9kYXRlAAAAAAAGAAAAAAAAAAxpc3N1ZV9udW1iZXIAAAAGAAAAAAAAAA1tYXR1cml0eV9kYXRlAAAAAAAABgAAAAAAAAAMcmVtdW5lcmF0aW9uAAAH0AAAAAxSZW11bmVyYXRpb24=",
+        "AAAAAgAAAAAAAAAAAAAAB0luZGV4ZXIAAAAAAwAAAAAAAAAAAAAACVByZWZpeGFkbwAAAAAAAAAAAAAAAAAAA0NkaQAAAAAAAAAAAAAAAARJcGNh",
+        "AAAAAgAAAAAAAAAAAAAACERheUNvdW50AAAAAwAAAAAAAAAAAAAABUR1MjUyAAAAAAAAAAAAAAAAAAAJVGhpcnR5MzYwAAAAAAAAAAAAAAAAAAAGQWN0MzY1AAA=",
+        "AAAAAQAAAAAAAAAAAAAACUJvbmRSYW5nZQAAAAAAAAIAAAAAAAAACGZpcnN0X2lkAAAABgAAAAAAAAAIcXVhbnRpdHkAAAAG",
+        "AAAAAQAAAAAAAAAAAAAACUlzc3VlRGF0YQAAAAAAAAIAAAAAAAAACGludmVzdG9yAAAAEwAAAAAAAAAJcHJpbmNpcGFsAAAAAAAABg==",
+        "AAAAAgAAAAAAAAAAAAAACkJvbmRTdGF0dXMAAAAAAAUAAAAAAAAAAAAAAApSZWdpc3RlcmVkAAAAAAAAAAAAAAAAAAdPZmZlcmVkAAAAAAAAAAAAAAAABklzc3VlZAAAAAAAAAAAAAAAAAAHTWF0dXJlZAAAAAAAAAAAAAAAAAhSZWRlZW1lZA==",
+        "AAAAAQAAAAAAAAAAAAAADFJlbXVuZXJhdGlvbgAAAAQAAAAAAAAACWRheV9jb3VudAAAAAAAB9AAAAAIRGF5Q291bnQAAAAAAAAAEGluZGV4X3BlcmNlbnRhZ2UAAAALAAAAAAAAAAdpbmRleGVyAAAAB9AAAAAHSW5kZXhlcgAAAAAAAAAABnNwcmVhZAAAAAAACw==" ]),
       options
     )
   }
   public readonly fromJSON = {
-    initialize: this.txFromJSON<null>,
+    bond_status: this.txFromJSON<BondStatus>,
+        is_operator: this.txFromJSON<boolean>,
         mint_tokens: this.txFromJSON<null>,
-        transfer_bond_ownership: this.txFromJSON<null>,
+        issue_volume: this.txFromJSON<u64>,
+        set_operator: this.txFromJSON<null>,
         get_bond_details: this.txFromJSON<Bond>,
+        operator_transfer: this.txFromJSON<null>,
         get_investor_bonds: this.txFromJSON<Array<u64>>,
+        get_investor_ranges: this.txFromJSON<Array<BondRange>>,
         get_investor_by_bond_id: this.txFromJSON<string>,
-        issue_volume: this.txFromJSON<u64>,
-        set_bond_template: this.txFromJSON<null>
+        transfer_bond_ownership: this.txFromJSON<null>
   }
 }
\ No newline at end of file